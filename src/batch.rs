@@ -0,0 +1,575 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
+#[cfg(feature = "blocking")]
+use crate::Client;
+use crate::{
+    cache::{Fetched, ResponseSource},
+    errors::{Error, RecordError, Result},
+    schedule::{order, Lecture, LectureType, Request, ScheduleKey},
+    utils::Period,
+};
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "blocking")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+/** Schedules fetched by a batch, with the result of every request keyed by its entity.
+
+A failed request doesn't abort the batch, its error is kept next to the successful results.
+
+# Examples
+```
+# use nure_tools::{
+#     batch::BatchSchedule,
+#     groups::Group,
+#     schedule::{Request, ScheduleKey},
+#     transport::FixtureTransport,
+#     utils::Period,
+#     Client, Error,
+# };
+let transport = FixtureTransport::new()
+    .with_json(
+        "/lists/groups",
+        r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}, {"id": 10887036, "name": "ПЗПІ-23-3"}]"#,
+    )
+    .with_json(
+        "/schedule/groups/10887035",
+        r#"[{
+            "id": 1,
+            "startTime": 1704180600,
+            "endTime": 1704186300,
+            "auditory": "287",
+            "numberPair": 2,
+            "type": "Лк",
+            "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+            "teachers": [],
+            "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+        }]"#,
+    );
+let client = Client::builder().transport(transport).parallelism(8).build()?;
+
+let requests: Vec<Request> = client
+    .find_group("пзпі-23")?
+    .into_iter()
+    .map(Request::Group)
+    .collect();
+let batch: BatchSchedule =
+    client.fetch_schedules(requests, Period::from_string("2024-01-01", "2024-01-07")?);
+
+assert_eq!(batch.len(), 2);
+assert_eq!(batch.lectures(&ScheduleKey::Group(10887035)).map(|lectures| lectures.len()), Some(1));
+assert!(batch.error(&ScheduleKey::Group(10887036)).is_some());
+assert!(!batch.is_complete());
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Default)]
+pub struct BatchSchedule {
+    pub results: HashMap<ScheduleKey, Result<Fetched<Vec<Lecture>>>>,
+}
+
+impl BatchSchedule {
+    /** Result of the request for `key`, `None` if it was not in the batch.
+     **/
+    pub fn get(&self, key: &ScheduleKey) -> Option<&Result<Fetched<Vec<Lecture>>>> {
+        self.results.get(key)
+    }
+
+    /** Lectures of `key`, `None` if its request failed or was not in the batch.
+     **/
+    pub fn lectures(&self, key: &ScheduleKey) -> Option<&[Lecture]> {
+        match self.results.get(key) {
+            Some(Ok(fetched)) => Some(&fetched.data),
+            _ => None,
+        }
+    }
+
+    /** Error of the request for `key`, `None` if it succeeded or was not in the batch.
+     **/
+    pub fn error(&self, key: &ScheduleKey) -> Option<&Error> {
+        match self.results.get(key) {
+            Some(Err(error)) => Some(error),
+            _ => None,
+        }
+    }
+
+    /** Successful requests with their schedules.
+     **/
+    pub fn successes(&self) -> impl Iterator<Item = (&ScheduleKey, &Fetched<Vec<Lecture>>)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().ok().map(|fetched| (key, fetched)))
+    }
+
+    /** Failed requests with their errors.
+     **/
+    pub fn failures(&self) -> impl Iterator<Item = (&ScheduleKey, &Error)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().err().map(|error| (key, error)))
+    }
+
+    /** Say if every request of the batch succeeded.
+     **/
+    pub fn is_complete(&self) -> bool {
+        self.results.values().all(|result| result.is_ok())
+    }
+
+    /** Number of distinct entities in the batch.
+     **/
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /** Say if the batch had no requests.
+     **/
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
+/** How long periods are split before their schedule is fetched.
+
+Every chunk is a separate request, checked against [`ClientBuilder::max_period_span`] on its own.
+
+# Variants
+ * `Whole` - one request for the whole period.
+ * `Daily` - one request per day.
+ * `Weekly` - one request per week, from Monday to Sunday.
+ * `Every` - one request per given duration, counted from the start of the period.
+
+[`ClientBuilder::max_period_span`]: `crate::ClientBuilder::max_period_span`
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Chunking {
+    #[default]
+    Whole,
+    Daily,
+    Weekly,
+    Every(std::time::Duration),
+}
+
+impl Chunking {
+    /** Split `period` into chunks, [`Chunking::Whole`] and zero durations keep it whole.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::{batch::Chunking, utils::Period};
+    use std::time::Duration;
+
+    let period: Period = Period::from_string("2024-01-01T00:00:00+02:00", "2024-01-11T00:00:00+02:00")?;
+
+    assert_eq!(Chunking::Whole.split(&period).len(), 1);
+    assert_eq!(Chunking::Daily.split(&period).len(), 10);
+    assert_eq!(Chunking::Weekly.split(&period).len(), 2);
+    assert_eq!(Chunking::Every(Duration::from_secs(4 * 24 * 60 * 60)).split(&period).len(), 3);
+    assert_eq!(Chunking::Every(Duration::from_secs(1_000_000_000_000_000)).split(&period).len(), 1);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn split(&self, period: &Period) -> Vec<Period> {
+        match self {
+            Self::Whole => vec![period.clone()],
+            Self::Daily => period.split_by_day().collect(),
+            Self::Weekly => period.split_by_week().collect(),
+            Self::Every(span) => {
+                let step = match chrono::Duration::from_std(*span) {
+                    Ok(step) if step > chrono::Duration::zero() => step,
+                    _ => return vec![period.clone()],
+                };
+
+                let mut chunks: Vec<Period> = Vec::new();
+                let mut cursor = period.start_time;
+                while cursor < period.end_time {
+                    // Steps past the last representable time end the period instead.
+                    let end_time = cursor
+                        .checked_add_signed(step)
+                        .map_or(period.end_time, |end_time| {
+                            std::cmp::min(end_time, period.end_time)
+                        });
+                    chunks.push(Period {
+                        start_time: cursor,
+                        end_time,
+                    });
+                    cursor = end_time;
+                }
+                chunks
+            }
+        }
+    }
+}
+
+/** Schedule of a long period fetched in chunks, see [`Client::fetch_schedule_chunked`].
+
+`lectures` are de-duplicated and in chronological order, `failures` lists the chunks that couldn't be fetched
+with their errors, so the lectures of those chunks are missing.
+`source` is [`ResponseSource::StaleCache`] if any chunk came from a stale cache,
+[`ResponseSource::Network`] if any came from the network, and [`ResponseSource::FreshCache`] otherwise.
+
+[`Client::fetch_schedule_chunked`]: `crate::Client::fetch_schedule_chunked`
+**/
+#[derive(Debug)]
+pub struct ChunkedSchedule {
+    pub lectures: Vec<Lecture>,
+    pub source: ResponseSource,
+    pub warnings: Vec<RecordError>,
+    pub failures: Vec<(Period, Error)>,
+}
+
+impl ChunkedSchedule {
+    /** Say if every chunk was fetched.
+     **/
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /** Turn into the result of a single fetch, failing with the error of the first failed chunk.
+     **/
+    pub fn into_fetched(self) -> Result<Fetched<Vec<Lecture>>> {
+        if let Some((_, error)) = self.failures.into_iter().next() {
+            return Err(error);
+        }
+
+        Ok(Fetched::new(self.lectures, self.source).with_warnings(self.warnings))
+    }
+
+    pub(crate) fn merge(results: Vec<(Period, Result<Fetched<Vec<Lecture>>>)>) -> Self {
+        let mut schedule = Self {
+            lectures: Vec::new(),
+            source: ResponseSource::FreshCache,
+            warnings: Vec::new(),
+            failures: Vec::new(),
+        };
+        let mut seen: HashSet<LectureKey> = HashSet::new();
+
+        for (chunk, result) in results {
+            let fetched = match result {
+                Ok(fetched) => fetched,
+                Err(error) => {
+                    schedule.failures.push((chunk, error));
+                    continue;
+                }
+            };
+
+            schedule.source = match (schedule.source, fetched.source) {
+                (ResponseSource::StaleCache, _) | (_, ResponseSource::StaleCache) => {
+                    ResponseSource::StaleCache
+                }
+                (ResponseSource::Network, _) | (_, ResponseSource::Network) => {
+                    ResponseSource::Network
+                }
+                _ => ResponseSource::FreshCache,
+            };
+            schedule.warnings.extend(fetched.warnings);
+            schedule.lectures.extend(
+                fetched
+                    .data
+                    .into_iter()
+                    .filter(|lecture| seen.insert(lecture_key(lecture))),
+            );
+        }
+
+        schedule.lectures.sort_by_key(order);
+        schedule
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Get the schedules of many groups, teachers or lecture rooms for the same `period`.
+
+    Requests are sent from [`ClientBuilder::parallelism`] threads at most, see [`BatchSchedule`] for an example.
+    Requests for the same entity are sent once.
+
+    [`ClientBuilder::parallelism`]: `crate::ClientBuilder::parallelism`
+    **/
+    pub fn fetch_schedules(&self, requests: Vec<Request>, period: Period) -> BatchSchedule {
+        let requests = unique(requests);
+        let results = run_parallel(self.parallelism, requests, |request| {
+            (request.key(), self.fetch_schedule(request, period.clone()))
+        });
+
+        BatchSchedule {
+            results: results.into_iter().collect(),
+        }
+    }
+
+    /** Get schedule of a long `period` in chunks.
+
+    `period` is split according to the [`Chunking`] of the client,
+    chunks are fetched from [`ClientBuilder::parallelism`] threads at most.
+    Lectures returned by more than one chunk are kept once, and failed chunks are reported instead of failing the whole call.
+
+    [`Client::fetch_schedule`] does the same when the client has a [`Chunking`], but fails if any chunk fails.
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     batch::{ChunkedSchedule, Chunking},
+    #     groups::Group,
+    #     retry::RetryPolicy,
+    #     schedule::Request,
+    #     transport::{FixtureTransport, TransportResponse},
+    #     utils::Period,
+    #     Client, Error,
+    # };
+    let lecture = |start: i64, kind: &str| {
+        format!(
+            r#"{{"startTime": {}, "endTime": {}, "auditory": "287", "numberPair": 2, "type": "{}",
+                "groups": [], "teachers": [], "subject": {{"id": 2, "title": "ООП", "brief": "ООП"}}}}"#,
+            start,
+            start + 5700,
+            kind
+        )
+    };
+    let (first, second, third) = (1704094200, 1704699000, 1705303800);
+
+    let path = "/schedule/groups/10887035";
+    let transport = FixtureTransport::new()
+        .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#)
+        .with_json(
+            path,
+            format!("[{}, {}, {}]", lecture(second, "Лк"), lecture(first, "Лк"), lecture(first, "Лб")),
+        )
+        .with_json(path, format!("[{}, {}]", lecture(second, "Лк"), lecture(third, "Лк")))
+        .with_response(path, TransportResponse::new(500, ""));
+    let client = Client::builder()
+        .transport(transport)
+        .retry_policy(RetryPolicy::none())
+        .chunking(Chunking::Weekly)
+        .parallelism(1)
+        .build()?;
+
+    let group: Group = client.find_exect_group("пзпі-23-2")?;
+    let period = Period::from_string("2024-01-01T00:00:00+02:00", "2024-01-21T00:00:00+02:00")?;
+    let schedule: ChunkedSchedule = client.fetch_schedule_chunked(Request::Group(group), period)?;
+
+    let starts: Vec<i64> = schedule
+        .lectures
+        .iter()
+        .map(|lecture| lecture.period.start_time.timestamp())
+        .collect();
+    // The lecture and the laboratory work in the same slot are different lectures.
+    assert_eq!(starts, vec![first, first, second, third]);
+    assert_eq!(schedule.failures.len(), 1);
+    assert_eq!(schedule.failures[0].0.start_time.to_string(), "2024-01-15 00:00:00 EET");
+    # Ok::<(), Error>(())
+    ```
+
+    # Errors
+    This function fails if:
+     * [`Period::validate`] fails.
+
+    [`ClientBuilder::parallelism`]: `crate::ClientBuilder::parallelism`
+    **/
+    pub fn fetch_schedule_chunked(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<ChunkedSchedule> {
+        period.validate()?;
+
+        let results = run_parallel(self.parallelism, self.chunking.split(&period), |chunk| {
+            let result = self.fetch_schedule_once(request.clone(), chunk.clone());
+            (chunk, result)
+        });
+
+        Ok(ChunkedSchedule::merge(results))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Get the schedules of many groups, teachers or lecture rooms for the same `period`.
+
+    Async counterpart of [`Client::fetch_schedules`], at most [`ClientBuilder::parallelism`] requests run concurrently
+    as tokio tasks.
+
+    # Examples
+    ```no_run
+    # use nure_tools::{batch::BatchSchedule, schedule::Request, utils::Period, AsyncClient, Error};
+    # async fn run() -> Result<(), Error> {
+    let client: AsyncClient = AsyncClient::builder().parallelism(8).build_async()?;
+
+    let requests: Vec<Request> = client
+        .find_group("пзпі-23")
+        .await?
+        .into_iter()
+        .map(Request::Group)
+        .collect();
+    let batch: BatchSchedule = client.fetch_schedules(requests, Period::this_week()).await;
+
+    for (key, error) in batch.failures() {
+        println!("{:?}: {}", key, error);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [`Client::fetch_schedules`]: `crate::Client::fetch_schedules`
+    [`ClientBuilder::parallelism`]: `crate::ClientBuilder::parallelism`
+    **/
+    pub async fn fetch_schedules(&self, requests: Vec<Request>, period: Period) -> BatchSchedule {
+        let results = run_concurrent(self.parallelism, unique(requests), |request| {
+            let client = self.clone();
+            let period = period.clone();
+            async move { (request.key(), client.fetch_schedule(request, period).await) }
+        })
+        .await;
+
+        BatchSchedule {
+            results: results.into_iter().collect(),
+        }
+    }
+
+    /** Get schedule of a long `period` in chunks.
+
+    Async counterpart of [`Client::fetch_schedule_chunked`], at most [`ClientBuilder::parallelism`] chunks are fetched
+    concurrently as tokio tasks.
+
+    [`Client::fetch_schedule_chunked`]: `crate::Client::fetch_schedule_chunked`
+    [`ClientBuilder::parallelism`]: `crate::ClientBuilder::parallelism`
+    **/
+    pub async fn fetch_schedule_chunked(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<ChunkedSchedule> {
+        period.validate()?;
+
+        let results = run_concurrent(self.parallelism, self.chunking.split(&period), |chunk| {
+            let client = self.clone();
+            let request = request.clone();
+            async move {
+                let result = client.fetch_schedule_once(request, chunk.clone()).await;
+                (chunk, result)
+            }
+        })
+        .await;
+
+        Ok(ChunkedSchedule::merge(results))
+    }
+}
+
+/// Fields that tell lectures returned by overlapping chunks apart.
+type LectureKey = (i64, i64, i32, String, u8, LectureType, Vec<i32>);
+
+/** Helper function to identify `lecture` among the lectures of every chunk.
+
+Lecture type and groups are included, so a lecture and a laboratory work or two group sections
+in the same slot of a teacher or lecture room schedule are kept apart.
+**/
+fn lecture_key(lecture: &Lecture) -> LectureKey {
+    let mut groups: Vec<i32> = lecture.groups.iter().map(|group| group.id).collect();
+    groups.sort_unstable();
+
+    (
+        lecture.period.start_time.timestamp(),
+        lecture.period.end_time.timestamp(),
+        lecture.subject.id,
+        lecture.lecture_room.clone(),
+        lecture.number_pair,
+        lecture.lecture_type.clone(),
+        groups,
+    )
+}
+
+/** Helper function to drop requests for the same entity, keeping the first one.
+**/
+pub(crate) fn unique(requests: Vec<Request>) -> Vec<Request> {
+    let mut seen: HashSet<ScheduleKey> = HashSet::new();
+    requests
+        .into_iter()
+        .filter(|request| seen.insert(request.key()))
+        .collect()
+}
+
+/** Helper function to run `job` on every item from at most `limit` threads, keeping the order of the items.
+**/
+#[cfg(feature = "blocking")]
+pub(crate) fn run_parallel<T, R, F>(limit: usize, items: Vec<T>, job: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let items: Vec<Mutex<Option<T>>> = items
+        .into_iter()
+        .map(|item| Mutex::new(Some(item)))
+        .collect();
+    let results: Vec<Mutex<Option<R>>> = (0..count).map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+
+    std::thread::scope(|scope| {
+        for _ in 0..limit.clamp(1, count.max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= count {
+                    break;
+                }
+                let item = take(&items[index]);
+                if let Some(item) = item {
+                    let result = job(item);
+                    *lock(&results[index]) = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .filter_map(|result| take(&result))
+        .collect()
+}
+
+#[cfg(feature = "blocking")]
+fn take<T>(slot: &Mutex<Option<T>>) -> Option<T> {
+    lock(slot).take()
+}
+
+#[cfg(feature = "blocking")]
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/** Helper function to run the futures made by `job` for every item as at most `limit` tokio tasks, keeping the order of the items.
+**/
+#[cfg(feature = "async")]
+pub(crate) async fn run_concurrent<T, R, F, Fut>(limit: usize, items: Vec<T>, job: F) -> Vec<R>
+where
+    R: Send + 'static,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = R> + Send + 'static,
+{
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    let mut pending = items.into_iter().enumerate();
+    let mut running = tokio::task::JoinSet::new();
+
+    loop {
+        while running.len() < limit.max(1) {
+            let Some((index, item)) = pending.next() else {
+                break;
+            };
+            let future = job(item);
+            running.spawn(async move { (index, future.await) });
+        }
+
+        match running.join_next().await {
+            Some(Ok((index, result))) => results[index] = Some(result),
+            Some(Err(error)) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Some(Err(_)) => {}
+            None => break,
+        }
+    }
+
+    results.into_iter().flatten().collect()
+}
@@ -0,0 +1,345 @@
+use crate::{
+    errors::{Error, ParseError, Result},
+    schedule::Lecture,
+    utils::{Period, DEFAULT_TIME_ZONE},
+};
+use chrono::{Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/** Time slot of a single pair.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairSlot {
+    pub number: u8,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl PairSlot {
+    /** Length of the pair.
+     **/
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /** Say if `time` is within the pair, the end excluded.
+     **/
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        self.start <= time && time < self.end
+    }
+}
+
+/** Bell schedule of the university: start and end times of every pair, in Kyiv time.
+
+[`BellSchedule::nure`] is the standard NURE timetable, a custom one can be loaded with [`BellSchedule::new`]
+or [`BellSchedule::from_times`].
+
+# Examples
+```
+# use nure_tools::{bells::BellSchedule, errors::ParseError, Error};
+use chrono::{Duration, NaiveTime};
+
+let bells = BellSchedule::nure();
+let time = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+
+assert_eq!(bells.pair_at(time).map(|slot| slot.number), Some(2));
+assert_eq!(bells.time_until_break(time), Some(Duration::minutes(65)));
+assert_eq!(bells.next_pair(NaiveTime::from_hms_opt(11, 10, 0).unwrap()).map(|slot| slot.number), Some(3));
+
+let custom = BellSchedule::from_times(&[("08:00", "09:20"), ("09:30", "10:50")])?;
+assert_eq!(custom.slot(2).map(|slot| slot.start), NaiveTime::from_hms_opt(9, 30, 0));
+assert!(BellSchedule::from_times(&[("09:30", "10:50"), ("08:00", "09:20")]).is_err());
+assert!(matches!(
+    BellSchedule::from_times(&[("08:00", "08:01"); 256]),
+    Err(Error::Parse(ParseError::InvalidBellSchedule(_)))
+));
+
+# #[cfg(feature = "serde")]
+# {
+let json = serde_json::to_string(&custom)?;
+assert_eq!(serde_json::from_str::<BellSchedule>(&json)?, custom);
+assert!(serde_json::from_str::<BellSchedule>("[]").is_err());
+# }
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<PairSlot>", into = "Vec<PairSlot>")
+)]
+pub struct BellSchedule {
+    slots: Vec<PairSlot>,
+}
+
+impl BellSchedule {
+    /** Standard NURE bell schedule: 8 pairs of 95 minutes, from 07:45 to 21:45.
+     **/
+    pub fn nure() -> Self {
+        Self::from_times(&[
+            ("07:45", "09:20"),
+            ("09:30", "11:05"),
+            ("11:15", "12:50"),
+            ("13:10", "14:45"),
+            ("14:55", "16:30"),
+            ("16:40", "18:15"),
+            ("18:25", "20:00"),
+            ("20:10", "21:45"),
+        ])
+        .expect("standard bell schedule is valid")
+    }
+
+    /** Create a custom BellSchedule from the given slots.
+
+    # Errors
+    This function fails if:
+     * [`ParseError::InvalidBellSchedule`] - There are no slots, any slot ends before it starts,
+       or the slots overlap or are not in order.
+    **/
+    pub fn new(slots: Vec<PairSlot>) -> Result<Self> {
+        if slots.is_empty() {
+            return Err(invalid("no pairs"));
+        }
+
+        for slot in &slots {
+            if slot.start >= slot.end {
+                return Err(invalid(&format!(
+                    "pair {} ends before it starts",
+                    slot.number
+                )));
+            }
+        }
+
+        for pair in slots.windows(2) {
+            if pair[0].number >= pair[1].number || pair[0].end > pair[1].start {
+                return Err(invalid(&format!(
+                    "pairs {} and {} overlap or are out of order",
+                    pair[0].number, pair[1].number
+                )));
+            }
+        }
+
+        Ok(Self { slots })
+    }
+
+    /** Create a custom BellSchedule from `("HH:MM", "HH:MM")` start and end times, pairs are numbered from 1.
+
+    # Errors
+    This function fails if:
+     * [`ParseError::InvalidStringProvided`] - Any of the times can't be parsed.
+     * [`ParseError::InvalidBellSchedule`] - There are more than 255 pairs.
+     * [`BellSchedule::new`] fails.
+    **/
+    pub fn from_times(times: &[(&str, &str)]) -> Result<Self> {
+        let mut slots: Vec<PairSlot> = Vec::new();
+
+        for (index, (start, end)) in times.iter().enumerate() {
+            let Ok(number) = u8::try_from(index + 1) else {
+                return Err(invalid(&format!(
+                    "{} pairs, at most {} are supported",
+                    times.len(),
+                    u8::MAX
+                )));
+            };
+
+            slots.push(PairSlot {
+                number,
+                start: parse_time(start)?,
+                end: parse_time(end)?,
+            });
+        }
+
+        Self::new(slots)
+    }
+
+    /** All pair slots in order.
+     **/
+    pub fn slots(&self) -> &[PairSlot] {
+        &self.slots
+    }
+
+    /** Slot of the pair with the given number.
+     **/
+    pub fn slot(&self, number: u8) -> Option<&PairSlot> {
+        self.slots.iter().find(|slot| slot.number == number)
+    }
+
+    /** Pair that is going on at `time`.
+     **/
+    pub fn pair_at(&self, time: NaiveTime) -> Option<&PairSlot> {
+        self.slots.iter().find(|slot| slot.contains(time))
+    }
+
+    /** First pair starting at or after `time`.
+     **/
+    pub fn next_pair(&self, time: NaiveTime) -> Option<&PairSlot> {
+        self.slots.iter().find(|slot| slot.start >= time)
+    }
+
+    /** [`Period`] of the pair with the given number on `date`, in Kyiv time.
+     **/
+    pub fn pair_period(&self, date: NaiveDate, number: u8) -> Option<Period> {
+        self.pair_period_in(date, number, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`BellSchedule::pair_period`], but with the slots read as local times of `time_zone`.
+     **/
+    pub fn pair_period_in(&self, date: NaiveDate, number: u8, time_zone: Tz) -> Option<Period> {
+        let slot = self.slot(number)?;
+        let at = |time: NaiveTime| {
+            time_zone
+                .from_local_datetime(&date.and_time(time))
+                .earliest()
+        };
+
+        Some(Period {
+            start_time: at(slot.start)?,
+            end_time: at(slot.end)?,
+        })
+    }
+
+    /** Breaks between consecutive pairs, as start and end times.
+     **/
+    pub fn breaks(&self) -> Vec<(NaiveTime, NaiveTime)> {
+        self.slots
+            .windows(2)
+            .map(|pair| (pair[0].end, pair[1].start))
+            .collect()
+    }
+
+    /** Time left until the end of the pair going on at `time`, `None` if there is no pair.
+     **/
+    pub fn time_until_break(&self, time: NaiveTime) -> Option<Duration> {
+        self.pair_at(time).map(|slot| slot.end - time)
+    }
+
+    /** Pair that is going on right now, in Kyiv time.
+     **/
+    pub fn current_pair(&self) -> Option<&PairSlot> {
+        self.current_pair_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`BellSchedule::current_pair`], but in `time_zone`.
+     **/
+    pub fn current_pair_in(&self, time_zone: Tz) -> Option<&PairSlot> {
+        self.pair_at(now(time_zone))
+    }
+
+    /** Time left until the next break right now, in Kyiv time, `None` if there is no pair.
+     **/
+    pub fn time_until_next_break(&self) -> Option<Duration> {
+        self.time_until_next_break_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`BellSchedule::time_until_next_break`], but in `time_zone`.
+     **/
+    pub fn time_until_next_break_in(&self, time_zone: Tz) -> Option<Duration> {
+        self.time_until_break(now(time_zone))
+    }
+
+    /** Check that the [`Period`] of `lecture` matches its declared pair.
+
+    The lecture has to start with its pair and end with the same or any later pair, as double pairs do.
+
+    # Examples
+    ```
+    # use nure_tools::{bells::BellSchedule, schedule::parse_lecture_json, Error};
+    let lecture = serde_json::json!({
+        "id": 1,
+        "startTime": 1704180600,
+        "endTime": 1704186300,
+        "auditory": "287",
+        "numberPair": 2,
+        "type": "Лк",
+        "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+        "teachers": [],
+        "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+    });
+    let mut wrong_pair = lecture.clone();
+    wrong_pair["numberPair"] = serde_json::json!(3);
+
+    let lectures = parse_lecture_json(vec![lecture, wrong_pair])?;
+    let bells = BellSchedule::nure();
+
+    assert!(bells.validate(&lectures[0]).is_ok());
+    assert!(bells.validate(&lectures[1]).is_err());
+    # Ok::<(), Error>(())
+    ```
+
+    # Errors
+    This function fails if:
+     * [`ParseError::PairMismatch`] - Lecture starts or ends outside the slots of its pair.
+
+    [`Period`]: `crate::utils::Period`
+    **/
+    pub fn validate(&self, lecture: &Lecture) -> Result<()> {
+        self.validate_in(lecture, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`BellSchedule::validate`], but with the slots read as local times of `time_zone`.
+     **/
+    pub fn validate_in(&self, lecture: &Lecture, time_zone: Tz) -> Result<()> {
+        let start = lecture.period.start_time.with_timezone(&time_zone).time();
+        let end = lecture.period.end_time.with_timezone(&time_zone).time();
+
+        let matches = self.slot(lecture.number_pair).is_some_and(|slot| {
+            slot.start == start
+                && self
+                    .slots
+                    .iter()
+                    .any(|other| other.number >= slot.number && other.end == end)
+        });
+
+        if matches {
+            Ok(())
+        } else {
+            Err(Error::from(ParseError::PairMismatch(
+                lecture.number_pair,
+                format!("{} - {}", start.format("%H:%M"), end.format("%H:%M")),
+            )))
+        }
+    }
+}
+
+/** Bell schedule from its slots, checked by [`BellSchedule::new`].
+
+With the `serde` feature a BellSchedule is (de)serialized as the list of its slots the same way.
+**/
+impl TryFrom<Vec<PairSlot>> for BellSchedule {
+    type Error = Error;
+
+    fn try_from(slots: Vec<PairSlot>) -> Result<Self> {
+        Self::new(slots)
+    }
+}
+
+impl From<BellSchedule> for Vec<PairSlot> {
+    fn from(bells: BellSchedule) -> Self {
+        bells.slots
+    }
+}
+
+impl Default for BellSchedule {
+    fn default() -> Self {
+        Self::nure()
+    }
+}
+
+fn parse_time(time: &str) -> Result<NaiveTime> {
+    match NaiveTime::parse_from_str(time.trim(), "%H:%M") {
+        Ok(parsed) => Ok(parsed),
+        Err(_) => Err(Error::from(ParseError::InvalidStringProvided(
+            String::from(time),
+        ))),
+    }
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::from(ParseError::InvalidBellSchedule(String::from(reason)))
+}
+
+fn now(time_zone: Tz) -> NaiveTime {
+    Utc::now().with_timezone(&time_zone).time()
+}
@@ -0,0 +1,484 @@
+use crate::{
+    errors::{RecordError, Result},
+    groups::{Group, GROUPS_PATH},
+    lecture_rooms::{LectureRoom, LECTURE_ROOMS_PATH},
+    teachers::{Teacher, TEACHERS_PATH},
+    transport::{TransportRequest, TransportResponse},
+};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Default time to live of the directory cache.
+pub const DEFAULT_DIRECTORY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/** In-memory cache of the groups, teachers and lecture rooms lists of a client.
+
+Shared between clones of the same client.
+**/
+#[derive(Debug)]
+pub(crate) struct DirectoryCache {
+    pub(crate) groups: Slot<Group>,
+    pub(crate) teachers: Slot<Teacher>,
+    pub(crate) lecture_rooms: Slot<LectureRoom>,
+}
+
+impl DirectoryCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            groups: Slot::new(ttl),
+            teachers: Slot::new(ttl),
+            lecture_rooms: Slot::new(ttl),
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.groups.clear();
+        self.teachers.clear();
+        self.lecture_rooms.clear();
+    }
+}
+
+/** Single cached list with the time it was fetched at.
+**/
+#[derive(Debug)]
+pub(crate) struct Slot<T> {
+    ttl: Duration,
+    entry: RwLock<Option<(Instant, Arc<Vec<T>>)>>,
+}
+
+impl<T> Slot<T> {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: RwLock::new(None),
+        }
+    }
+
+    /** Cached list, if it is younger than the time to live.
+     **/
+    pub(crate) fn get(&self) -> Option<Arc<Vec<T>>> {
+        let entry = match self.entry.read() {
+            Ok(entry) => entry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        match entry.as_ref() {
+            Some((fetched_at, items)) if fetched_at.elapsed() < self.ttl => Some(Arc::clone(items)),
+            _ => None,
+        }
+    }
+
+    /** Replace the cached list, unless it came from a stale cache.
+     **/
+    pub(crate) fn set(&self, items: Vec<T>, source: ResponseSource) -> Arc<Vec<T>> {
+        let items = Arc::new(items);
+        if source == ResponseSource::StaleCache {
+            return items;
+        }
+
+        let mut entry = match self.entry.write() {
+            Ok(entry) => entry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *entry = Some((Instant::now(), Arc::clone(&items)));
+
+        items
+    }
+
+    pub(crate) fn clear(&self) {
+        let mut entry = match self.entry.write() {
+            Ok(entry) => entry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *entry = None;
+    }
+}
+
+/** Where a result came from.
+# Variants
+ * `Network` - fresh response from the API.
+ * `FreshCache` - cached response that is still within its time to live.
+ * `StaleCache` - expired cached response, served because the API request failed with a retryable error.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseSource {
+    Network,
+    FreshCache,
+    StaleCache,
+}
+
+/** Result of a fetch together with its [`ResponseSource`].
+
+`warnings` lists the records skipped by a lenient client (see [`ClientBuilder::lenient`]) while parsing this result,
+lists served from the directory cache carry no warnings.
+
+[`ClientBuilder::lenient`]: `crate::ClientBuilder::lenient`
+**/
+#[derive(Debug, Clone)]
+pub struct Fetched<T> {
+    pub data: T,
+    pub source: ResponseSource,
+    pub warnings: Vec<RecordError>,
+}
+
+impl<T> Fetched<T> {
+    pub(crate) fn new(data: T, source: ResponseSource) -> Self {
+        Self {
+            data,
+            source,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_warnings(mut self, warnings: Vec<RecordError>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+}
+
+/** API endpoints the [`DiskCache`] distinguishes for time to live purposes.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    Groups,
+    Teachers,
+    LectureRooms,
+    Schedule,
+}
+
+impl Endpoint {
+    fn from_path(path: &str) -> Self {
+        match path {
+            GROUPS_PATH => Self::Groups,
+            TEACHERS_PATH => Self::Teachers,
+            LECTURE_ROOMS_PATH => Self::LectureRooms,
+            _ => Self::Schedule,
+        }
+    }
+
+    fn file_prefix(&self) -> &'static str {
+        match self {
+            Self::Groups => "groups",
+            Self::Teachers => "teachers",
+            Self::LectureRooms => "auditories",
+            Self::Schedule => "schedule",
+        }
+    }
+}
+
+/** Persistent on-disk cache of API responses.
+
+Responses are keyed by endpoint, entity id and [`Period`] bounds (i.e. request path and query),
+so a restarted process can answer instantly and still serve the last known data when the API is down.
+
+Every entry lives in its own json file inside the cache directory.
+When the total size of the directory exceeds the size cap, the oldest entries are removed.
+Any IO error is ignored, the cache then behaves as if the entry was missing.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::{
+#     cache::{DiskCache, Endpoint, ResponseSource},
+#     retry::RetryPolicy,
+#     transport::{FixtureTransport, TransportResponse},
+#     Client,
+# };
+use std::time::Duration;
+
+let directory = std::env::temp_dir().join("nure_tools_disk_cache_example");
+# let _ = std::fs::remove_dir_all(&directory);
+let disk_cache = DiskCache::new(&directory)
+    .ttl(Endpoint::Groups, Duration::ZERO)
+    .max_size(1024 * 1024);
+
+let transport = FixtureTransport::new()
+    .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#)
+    .with_response("/lists/groups", TransportResponse::new(500, ""))
+    .with_response("/lists/groups", TransportResponse::new(404, ""));
+let client = Client::builder()
+    .transport(transport)
+    .retry_policy(RetryPolicy::none())
+    .directory_ttl(Duration::ZERO)
+    .disk_cache(disk_cache)
+    .build()?;
+
+assert_eq!(client.fetch_groups()?.source, ResponseSource::Network);
+// API is down now, but the last known list is still there.
+let groups = client.fetch_groups()?;
+assert_eq!(groups.source, ResponseSource::StaleCache);
+assert_eq!(groups.data[0].name, "ПЗПІ-23-2");
+// Errors that retrying wouldn't fix are not hidden.
+assert!(client.fetch_groups().unwrap_err().is_not_found());
+# let _ = std::fs::remove_dir_all(&directory);
+# Ok::<(), Error>(())
+```
+
+[`Period`]: `crate::utils::Period`
+**/
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    directory: PathBuf,
+    ttls: HashMap<Endpoint, Duration>,
+    max_size: u64,
+}
+
+/** Cached response body loaded by a [`DiskCache`].
+**/
+#[derive(Debug, Clone)]
+pub(crate) struct CachedBody {
+    pub(crate) body: String,
+    pub(crate) fresh: bool,
+}
+
+impl DiskCache {
+    /** Create a new DiskCache storing its entries in `directory`.
+
+    Defaults are: 1 day time to live for the lists, 1 hour for schedules, 64 MiB size cap.
+    The directory is created on the first write.
+    **/
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            ttls: HashMap::from([
+                (Endpoint::Groups, Duration::from_secs(24 * 60 * 60)),
+                (Endpoint::Teachers, Duration::from_secs(24 * 60 * 60)),
+                (Endpoint::LectureRooms, Duration::from_secs(24 * 60 * 60)),
+                (Endpoint::Schedule, Duration::from_secs(60 * 60)),
+            ]),
+            max_size: 64 * 1024 * 1024,
+        }
+    }
+
+    /** Set the time to live of the entries of `endpoint`.
+     **/
+    pub fn ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+
+    /** Set the size cap of the cache directory in bytes.
+     **/
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /** Directory the entries are stored in.
+     **/
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /** Remove every entry of the cache.
+
+    # Errors
+    This function fails if the cache directory exists but can't be removed.
+    **/
+    pub fn clear(&self) -> std::io::Result<()> {
+        match fs::remove_dir_all(&self.directory) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn load(&self, request: &TransportRequest) -> Option<CachedBody> {
+        let key = cache_key(request);
+        let entry: Value =
+            serde_json::from_slice(&fs::read(self.entry_path(request, &key)).ok()?).ok()?;
+
+        if entry.get("key")?.as_str()? != key {
+            return None;
+        }
+
+        let stored_at = UNIX_EPOCH + Duration::from_secs(entry.get("storedAt")?.as_u64()?);
+        let age = SystemTime::now()
+            .duration_since(stored_at)
+            .unwrap_or(Duration::ZERO);
+        let ttl = self.ttls[&Endpoint::from_path(&request.path)];
+
+        Some(CachedBody {
+            body: String::from(entry.get("body")?.as_str()?),
+            fresh: age < ttl,
+        })
+    }
+
+    pub(crate) fn store(&self, request: &TransportRequest, body: &str) {
+        let key = cache_key(request);
+        let stored_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs();
+        let entry = json!({ "key": key, "storedAt": stored_at, "body": body });
+
+        // Unique per writer, so concurrent stores of the same entry don't write into each other's file.
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+        let path = self.entry_path(request, &key);
+        let temporary = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            WRITES.fetch_add(1, Ordering::Relaxed)
+        ));
+        if fs::create_dir_all(&self.directory).is_err()
+            || fs::write(&temporary, entry.to_string()).is_err()
+            || fs::rename(&temporary, &path).is_err()
+        {
+            let _ = fs::remove_file(&temporary);
+            return;
+        }
+
+        self.evict(&path);
+    }
+
+    fn entry_path(&self, request: &TransportRequest, key: &str) -> PathBuf {
+        self.directory.join(format!(
+            "{}-{:016x}.json",
+            Endpoint::from_path(&request.path).file_prefix(),
+            fnv1a(key.as_bytes())
+        ))
+    }
+
+    /** Helper function to remove the oldest entries until the cache fits into its size cap.
+
+    `keep` is never removed, so an entry that was just written survives coarse modification times.
+    **/
+    fn evict(&self, keep: &Path) {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return;
+        };
+
+        let mut files: Vec<(SystemTime, u64, PathBuf)> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let metadata = entry.metadata().ok()?;
+                let path = entry.path();
+                if !metadata.is_file() || path.extension()? != "json" || path == keep {
+                    return None;
+                }
+                Some((metadata.modified().ok()?, metadata.len(), path))
+            })
+            .collect();
+
+        let mut total: u64 = files.iter().map(|(_, size, _)| size).sum();
+        files.sort();
+
+        for (_, size, path) in files {
+            if total <= self.max_size {
+                break;
+            }
+            if fs::remove_file(path).is_ok() {
+                total -= size;
+            }
+        }
+    }
+}
+
+/** Helper function to turn the outcome of a request into json, falling back to a stale cached body.
+
+Successful responses are written to the disk cache.
+Only retryable errors fall back, see [`Error::is_retryable`].
+
+[`Error::is_retryable`]: `crate::Error::is_retryable`
+**/
+pub(crate) fn settle(
+    disk_cache: Option<&DiskCache>,
+    request: &TransportRequest,
+    outcome: Result<(TransportResponse, Value)>,
+    cached: Option<CachedBody>,
+) -> Result<Fetched<Value>> {
+    let error = match outcome {
+        Ok((response, value)) => {
+            if let Some(disk_cache) = disk_cache {
+                disk_cache.store(request, &response.body);
+            }
+            return Ok(Fetched::new(value, ResponseSource::Network));
+        }
+        Err(error) if error.is_retryable() => error,
+        Err(error) => return Err(error),
+    };
+
+    match cached.and_then(|cached| serde_json::from_str::<Value>(&cached.body).ok()) {
+        Some(value) => Ok(Fetched::new(value, ResponseSource::StaleCache)),
+        None => Err(error),
+    }
+}
+
+/** Helper function to look up `request` in the disk cache.
+
+Returns the parsed body if it is fresh, and the raw entry to fall back on otherwise.
+**/
+pub(crate) fn lookup(
+    disk_cache: Option<&DiskCache>,
+    request: &TransportRequest,
+) -> (Option<Fetched<Value>>, Option<CachedBody>) {
+    let Some(cached) = disk_cache.and_then(|disk_cache| disk_cache.load(request)) else {
+        return (None, None);
+    };
+
+    if cached.fresh {
+        if let Ok(value) = serde_json::from_str::<Value>(&cached.body) {
+            return (Some(Fetched::new(value, ResponseSource::FreshCache)), None);
+        }
+    }
+
+    (None, Some(cached))
+}
+
+/** Same as [`lookup`], but reads the disk cache on the blocking thread pool of tokio.
+**/
+#[cfg(feature = "async")]
+pub(crate) async fn lookup_async(
+    disk_cache: Option<&DiskCache>,
+    request: &TransportRequest,
+) -> (Option<Fetched<Value>>, Option<CachedBody>) {
+    let Some(disk_cache) = disk_cache.cloned() else {
+        return (None, None);
+    };
+
+    let request = request.clone();
+    tokio::task::spawn_blocking(move || lookup(Some(&disk_cache), &request))
+        .await
+        .unwrap_or((None, None))
+}
+
+/** Same as [`settle`], but writes the disk cache on the blocking thread pool of tokio.
+**/
+#[cfg(feature = "async")]
+pub(crate) async fn settle_async(
+    disk_cache: Option<&DiskCache>,
+    request: &TransportRequest,
+    outcome: Result<(TransportResponse, Value)>,
+    cached: Option<CachedBody>,
+) -> Result<Fetched<Value>> {
+    if let (Some(disk_cache), Ok((response, _))) = (disk_cache, &outcome) {
+        let (disk_cache, request, body) =
+            (disk_cache.clone(), request.clone(), response.body.clone());
+        let _ = tokio::task::spawn_blocking(move || disk_cache.store(&request, &body)).await;
+    }
+
+    settle(None, request, outcome, cached)
+}
+
+fn cache_key(request: &TransportRequest) -> String {
+    let mut key = request.path.clone();
+    for (name, value) in &request.query {
+        key.push_str(&format!("&{}={}", name, value));
+    }
+    key
+}
+
+/// Stable 64-bit FNV-1a hash, so file names survive toolchain updates.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,377 @@
+use crate::utils::{Period, DEFAULT_TIME_ZONE};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/** Semester of an academic year.
+# Variants
+ * `Autumn` - from September to the winter exam session.
+ * `Spring` - from February to the summer exam session.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Semester {
+    Autumn,
+    Spring,
+}
+
+/** Range of calendar days, both ends included.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /** Create a new DateRange instance.
+     **/
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        Self { start, end }
+    }
+
+    /** Say if `date` is within the range.
+     **/
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /** [`Period`] from the beginning of the first day to the end of the last one, in Kyiv time.
+     **/
+    pub fn period(&self) -> Period {
+        self.period_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`DateRange::period`], but in `time_zone`.
+
+    Days whose midnight is skipped by a DST change start at their first existing moment.
+
+    # Examples
+    ```
+    # use nure_tools::calendar::DateRange;
+    use chrono::NaiveDate;
+    use chrono_tz::Tz;
+
+    // Clocks in Santiago jump from 00:00 to 01:00 on this day.
+    let date = NaiveDate::from_ymd_opt(2024, 9, 8).unwrap();
+    let period = DateRange::new(date, date).period_in(Tz::America__Santiago);
+
+    assert_eq!(period.start_time.to_rfc3339(), "2024-09-08T01:00:00-03:00");
+    assert_eq!(period.end_time.date_naive(), date);
+    assert_eq!(period.end_time.to_rfc3339(), "2024-09-08T23:59:59.999999999-03:00");
+    ```
+    **/
+    pub fn period_in(&self, time_zone: Tz) -> Period {
+        Period {
+            start_time: beginning_of(self.start, time_zone),
+            end_time: end_of(self.end, time_zone),
+        }
+    }
+
+    /** Week of `date`, from Monday to Sunday.
+     **/
+    pub fn week_of(date: NaiveDate) -> Self {
+        let start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+        Self::new(start, start + Duration::days(6))
+    }
+
+    /** Month of `date`, from its first day to its last one.
+     **/
+    pub fn month_of(date: NaiveDate) -> Self {
+        let start = date.with_day(1).expect("first day of a month is valid");
+        let end = start
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+        Self::new(start, end)
+    }
+}
+
+/** Teaching weeks of a semester and its exam session.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Term {
+    pub teaching: DateRange,
+    pub session: DateRange,
+}
+
+impl Term {
+    /** Whole semester, from the first teaching day to the end of the exam session.
+     **/
+    pub fn range(&self) -> DateRange {
+        DateRange::new(self.teaching.start, self.session.end)
+    }
+}
+
+/** Calendar of a single academic year: semesters, exam sessions and holidays.
+
+[`AcademicCalendar::nure`] gives the usual NURE dates, every field can be changed to match the official order of a year.
+The [`Period`] constructors [`Period::this_semester`], [`Period::next_semester`], [`Period::this_academic_year`]
+and [`Period::exam_session`] are built on the NURE calendar,
+[`Period::this_semester_of`] and the other `_of` constructors take any calendar instead.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::calendar::{AcademicCalendar, Semester};
+use chrono::NaiveDate;
+
+let mut calendar = AcademicCalendar::nure(2023).unwrap();
+calendar.spring.teaching.start = NaiveDate::from_ymd_opt(2024, 2, 12).unwrap();
+calendar.holidays[0].end = NaiveDate::from_ymd_opt(2024, 2, 11).unwrap();
+
+let date = NaiveDate::from_ymd_opt(2024, 2, 6).unwrap();
+assert_eq!(calendar.semester_on(date), None);
+assert!(calendar.is_holiday(date));
+assert_eq!(calendar.next_semester_after(date), Some(Semester::Spring));
+
+// Lectures of the whole spring semester.
+let period = calendar.semester(Semester::Spring);
+assert_eq!(period.start_time.date_naive(), NaiveDate::from_ymd_opt(2024, 2, 12).unwrap());
+assert_eq!(calendar.teaching_week(NaiveDate::from_ymd_opt(2024, 2, 21).unwrap()), Some(2));
+
+assert!(AcademicCalendar::nure(i32::MAX).is_none());
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcademicCalendar {
+    pub autumn: Term,
+    pub spring: Term,
+    pub holidays: Vec<DateRange>,
+}
+
+impl AcademicCalendar {
+    /** NURE calendar of the academic year starting in September of `year`.
+
+    Autumn semester runs from September 1 to December 24 with the session until January 19,
+    spring semester from February 3 to May 31 with the session in June.
+    Winter holidays fill the gap between the semesters, summer holidays last until the end of August.
+
+    `None` if any of the dates can't be represented, i.e. for years far outside of [`MIN_YEAR`] and [`MAX_YEAR`].
+
+    [`MIN_YEAR`]: `crate::utils::MIN_YEAR`
+    [`MAX_YEAR`]: `crate::utils::MAX_YEAR`
+    **/
+    pub fn nure(year: i32) -> Option<Self> {
+        let date = |year: i32, month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day);
+        let next_year = year.checked_add(1)?;
+
+        Some(Self {
+            autumn: Term {
+                teaching: DateRange::new(date(year, 9, 1)?, date(year, 12, 24)?),
+                session: DateRange::new(date(year, 12, 25)?, date(next_year, 1, 19)?),
+            },
+            spring: Term {
+                teaching: DateRange::new(date(next_year, 2, 3)?, date(next_year, 5, 31)?),
+                session: DateRange::new(date(next_year, 6, 1)?, date(next_year, 6, 30)?),
+            },
+            holidays: vec![
+                DateRange::new(date(next_year, 1, 20)?, date(next_year, 2, 2)?),
+                DateRange::new(date(next_year, 7, 1)?, date(next_year, 8, 31)?),
+            ],
+        })
+    }
+
+    /** NURE calendar of the academic year `date` belongs to, academic years start in September.
+
+    `None` if [`AcademicCalendar::nure`] is.
+    **/
+    pub fn nure_on(date: NaiveDate) -> Option<Self> {
+        if date.month() >= 9 {
+            Self::nure(date.year())
+        } else {
+            Self::nure(date.year() - 1)
+        }
+    }
+
+    /** NURE calendar of the current academic year, in Kyiv time.
+     **/
+    pub fn nure_current() -> Self {
+        Self::nure_current_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`AcademicCalendar::nure_current`], but for the current day in `time_zone`.
+     **/
+    pub fn nure_current_in(time_zone: Tz) -> Self {
+        Self::nure_on(today_in(time_zone)).expect("NURE calendar of the current year is valid")
+    }
+
+    /** Dates of `semester`.
+     **/
+    pub fn term(&self, semester: Semester) -> &Term {
+        match semester {
+            Semester::Autumn => &self.autumn,
+            Semester::Spring => &self.spring,
+        }
+    }
+
+    /** Whole `semester` including its exam session, in Kyiv time.
+     **/
+    pub fn semester(&self, semester: Semester) -> Period {
+        self.semester_in(semester, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`AcademicCalendar::semester`], but in `time_zone`.
+     **/
+    pub fn semester_in(&self, semester: Semester, time_zone: Tz) -> Period {
+        self.term(semester).range().period_in(time_zone)
+    }
+
+    /** Exam session of `semester`, in Kyiv time.
+     **/
+    pub fn session(&self, semester: Semester) -> Period {
+        self.session_in(semester, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`AcademicCalendar::session`], but in `time_zone`.
+     **/
+    pub fn session_in(&self, semester: Semester, time_zone: Tz) -> Period {
+        self.term(semester).session.period_in(time_zone)
+    }
+
+    /** Academic year, from the first day of the autumn semester to the end of the spring session, in Kyiv time.
+     **/
+    pub fn academic_year(&self) -> Period {
+        self.academic_year_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`AcademicCalendar::academic_year`], but in `time_zone`.
+     **/
+    pub fn academic_year_in(&self, time_zone: Tz) -> Period {
+        DateRange::new(self.autumn.teaching.start, self.spring.session.end).period_in(time_zone)
+    }
+
+    /** Semester `date` belongs to, `None` during holidays.
+     **/
+    pub fn semester_on(&self, date: NaiveDate) -> Option<Semester> {
+        [Semester::Autumn, Semester::Spring]
+            .into_iter()
+            .find(|semester| self.term(*semester).range().contains(date))
+    }
+
+    /** First semester of this calendar starting after `date`.
+     **/
+    pub fn next_semester_after(&self, date: NaiveDate) -> Option<Semester> {
+        [Semester::Autumn, Semester::Spring]
+            .into_iter()
+            .find(|semester| self.term(*semester).teaching.start > date)
+    }
+
+    /** Semester `date` belongs to, or the one starting next during holidays.
+
+    `None` after the end of the spring semester.
+    **/
+    pub fn semester_around(&self, date: NaiveDate) -> Option<Semester> {
+        self.semester_on(date)
+            .or_else(|| self.next_semester_after(date))
+    }
+
+    /** Semester whose exam session is going on on `date` or comes next.
+
+    `None` after the end of the spring session.
+    **/
+    pub fn session_around(&self, date: NaiveDate) -> Option<Semester> {
+        [Semester::Autumn, Semester::Spring]
+            .into_iter()
+            .find(|semester| self.term(*semester).session.end >= date)
+    }
+
+    /** Say if `date` is within an exam session.
+     **/
+    pub fn is_session(&self, date: NaiveDate) -> bool {
+        self.autumn.session.contains(date) || self.spring.session.contains(date)
+    }
+
+    /** Say if `date` is within holidays.
+     **/
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.iter().any(|holiday| holiday.contains(date))
+    }
+
+    /** Number of the teaching week of `date` within its semester, starting from 1.
+
+    `None` outside of teaching weeks, i.e. during sessions and holidays.
+    **/
+    pub fn teaching_week(&self, date: NaiveDate) -> Option<u32> {
+        let teaching = [&self.autumn.teaching, &self.spring.teaching]
+            .into_iter()
+            .find(|teaching| teaching.contains(date))?;
+        let first_monday = teaching.start.week(chrono::Weekday::Mon).first_day();
+
+        Some(((date - first_monday).num_days() / 7 + 1) as u32)
+    }
+}
+
+impl Default for AcademicCalendar {
+    fn default() -> Self {
+        Self::nure_current()
+    }
+}
+
+/** Helper function to find the NURE calendar and semester that start next after `date`.
+**/
+pub(crate) fn upcoming_semester(date: NaiveDate) -> Option<(AcademicCalendar, Semester)> {
+    let calendar = AcademicCalendar::nure_on(date)?;
+    match calendar.next_semester_after(date) {
+        Some(semester) => Some((calendar, semester)),
+        None => Some((
+            AcademicCalendar::nure(calendar.autumn.teaching.start.year() + 1)?,
+            Semester::Autumn,
+        )),
+    }
+}
+
+/** Helper function to find the NURE calendar and semester whose exam session is going on or comes next after `date`.
+**/
+pub(crate) fn upcoming_session(date: NaiveDate) -> Option<(AcademicCalendar, Semester)> {
+    let calendar = AcademicCalendar::nure_on(date)?;
+    match calendar.session_around(date) {
+        Some(semester) => Some((calendar, semester)),
+        None => Some((
+            AcademicCalendar::nure(calendar.autumn.teaching.start.year() + 1)?,
+            Semester::Autumn,
+        )),
+    }
+}
+
+/** Today in `time_zone`.
+**/
+pub(crate) fn today_in(time_zone: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&time_zone).date_naive()
+}
+
+/// Longest DST gap looked through when a local time doesn't exist, in minutes.
+const MAX_DST_GAP: i64 = 180;
+
+/** First moment of `date` in `time_zone`, the first existing one if midnight is skipped by a DST change.
+**/
+pub(crate) fn beginning_of(date: NaiveDate, time_zone: Tz) -> DateTime<Tz> {
+    let first = date.and_time(NaiveTime::MIN);
+
+    (0..=MAX_DST_GAP)
+        .find_map(|minutes| {
+            time_zone
+                .from_local_datetime(&(first + Duration::minutes(minutes)))
+                .earliest()
+        })
+        .unwrap_or_else(|| Utc.from_utc_datetime(&first).with_timezone(&time_zone))
+}
+
+/** Last moment of `date` in `time_zone`, the last existing one if the end of the day is skipped by a DST change.
+**/
+pub(crate) fn end_of(date: NaiveDate, time_zone: Tz) -> DateTime<Tz> {
+    let last = date
+        .and_hms_nano_opt(23, 59, 59, 999_999_999)
+        .expect("last moment of a day is valid");
+
+    (0..=MAX_DST_GAP)
+        .find_map(|minutes| {
+            time_zone
+                .from_local_datetime(&(last - Duration::minutes(minutes)))
+                .latest()
+        })
+        .unwrap_or_else(|| Utc.from_utc_datetime(&last).with_timezone(&time_zone))
+}
@@ -0,0 +1,835 @@
+use crate::batch::Chunking;
+#[cfg(feature = "blocking")]
+use crate::cache::{lookup, settle};
+#[cfg(feature = "async")]
+use crate::cache::{lookup_async, settle_async};
+use crate::cache::{DirectoryCache, DiskCache, Fetched, DEFAULT_DIRECTORY_TTL};
+use crate::errors::{Error, RequestError, Result};
+use crate::retry::{exhausted, RetryPolicy};
+#[cfg(feature = "async")]
+use crate::transport::{AsyncReqwestTransport, AsyncTransport};
+#[cfg(feature = "blocking")]
+use crate::transport::{ReqwestTransport, Transport};
+use crate::transport::{TransportRequest, TransportResponse};
+use crate::utils::DEFAULT_TIME_ZONE;
+use chrono_tz::Tz;
+#[cfg(feature = "blocking")]
+use reqwest::blocking;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde_json::Value;
+#[cfg(feature = "blocking")]
+use std::sync::OnceLock;
+use std::{fmt, sync::Arc, time::Duration};
+
+/// Base URL of the public Mindenit API.
+pub const DEFAULT_BASE_URL: &str = "https://api.mindenit.tech";
+
+/// Default number of requests a batch sends at the same time.
+pub const DEFAULT_PARALLELISM: usize = 4;
+
+/** Mindenit API client.
+
+Owns the [`Transport`] every request is sent through (by default a reused `reqwest` client pointed at the base URL),
+and exposes every API operation as a method.
+
+Groups, teachers and lecture rooms lists are cached inside the client for [`ClientBuilder::directory_ttl`],
+so repeated lookups hit the network only once. Clones of a client share the same cache.
+The free functions from [`groups`], [`teachers`], [`lecture_rooms`] and [`schedule`] are thin wrappers over a default client.
+
+# Examples
+```no_run
+# use nure_tools::Error;
+# use nure_tools::{groups::Group, Client};
+use std::time::Duration;
+
+let client: Client = Client::builder()
+    .base_url("https://api.mindenit.tech")
+    .timeout(Duration::from_secs(10))
+    .build()?;
+
+let groups: Vec<Group> = client.find_group("пзпі-23-2")?;
+println!("{:#?}", groups);
+# Ok::<(), Error>(())
+```
+
+Cached lookups:
+```
+# use nure_tools::Error;
+# use nure_tools::{transport::FixtureTransport, Client};
+let transport = FixtureTransport::new()
+    .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#);
+let client = Client::builder().transport(transport.clone()).build()?;
+
+client.find_group("пзпі")?;
+client.find_exect_group("пзпі-23-2")?;
+assert_eq!(transport.requests().len(), 1);
+
+client.invalidate();
+client.get_groups()?;
+assert_eq!(transport.requests().len(), 2);
+# Ok::<(), Error>(())
+```
+
+[`groups`]: `crate::groups`
+[`teachers`]: `crate::teachers`
+[`lecture_rooms`]: `crate::lecture_rooms`
+[`schedule`]: `crate::schedule`
+**/
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Client {
+    transport: Arc<dyn Transport>,
+    retry_policy: RetryPolicy,
+    pub(crate) directory: Arc<DirectoryCache>,
+    disk_cache: Option<DiskCache>,
+    pub(crate) lenient: bool,
+    pub(crate) resolve_lecture_rooms: bool,
+    pub(crate) time_zone: Tz,
+    pub(crate) max_period_span: Option<Duration>,
+    pub(crate) parallelism: usize,
+    pub(crate) chunking: Chunking,
+}
+
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Create a new [`ClientBuilder`].
+     **/
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /** Helper function to send a Get request to `path` of the API and return its json body.
+
+    Failed requests are retried according to the [`RetryPolicy`] of the client.
+    If the client has a [`DiskCache`], fresh cached responses are returned without a request,
+    and stale ones are returned if the request fails.
+
+    You probably will never use it, but you can if you want, see example in [`get_groups`] method source.
+
+    # Errors
+    This function fails if:
+     * [`Error::Transport`] - Get request fails.
+     * [`Error::Timeout`] - Get request times out.
+     * [`Error::Decode`] - Server returns value not in json format.
+     * [`Error::RateLimited`] - Server returns 429.
+     * [`RequestError::BadResponse`] - Server returns any response except 200.
+
+    If the request was attempted more than once, the error is wrapped into [`Error::AttemptsExhausted`].
+
+    [`get_groups`]: `Client::get_groups`
+    **/
+    pub fn get_json(&self, path: &str, query: &[(&str, String)]) -> Result<Value> {
+        Ok(self.fetch_json(path, query)?.data)
+    }
+
+    /** Same as [`get_json`], but also says where the json came from.
+
+    [`get_json`]: `Client::get_json`
+    **/
+    pub fn fetch_json(&self, path: &str, query: &[(&str, String)]) -> Result<Fetched<Value>> {
+        let request = TransportRequest::new(path, query);
+        let (fresh, cached) = lookup(self.disk_cache.as_ref(), &request);
+        if let Some(fresh) = fresh {
+            return Ok(fresh);
+        }
+
+        let outcome = self.send(&request);
+        settle(self.disk_cache.as_ref(), &request, outcome, cached)
+    }
+
+    fn send(&self, request: &TransportRequest) -> Result<(TransportResponse, Value)> {
+        let mut attempt: u32 = 1;
+
+        loop {
+            let outcome = self.transport.get(request);
+            if self.retry_policy.should_retry(attempt, &outcome) {
+                std::thread::sleep(self.retry_policy.delay(attempt, &outcome));
+                attempt += 1;
+                continue;
+            }
+
+            return outcome
+                .and_then(|response| response.json().map(|value| (response, value)))
+                .map_err(|error| exhausted(error, attempt));
+        }
+    }
+
+    /** Download groups, teachers and lecture rooms lists again, replacing the cached ones.
+
+    # Errors
+    This function fails if any of the lists can't be downloaded, see [`get_json`].
+
+    [`get_json`]: `Client::get_json`
+    **/
+    pub fn refresh(&self) -> Result<()> {
+        self.refresh_groups()?;
+        self.refresh_teachers()?;
+        self.refresh_lecture_rooms()?;
+        Ok(())
+    }
+
+    /** Drop the cached groups, teachers and lecture rooms lists.
+
+    Next lookup downloads them again.
+    **/
+    pub fn invalidate(&self) {
+        self.directory.clear();
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("retry_policy", &self.retry_policy)
+            .field("disk_cache", &self.disk_cache)
+            .field("lenient", &self.lenient)
+            .field("resolve_lecture_rooms", &self.resolve_lecture_rooms)
+            .field("time_zone", &self.time_zone)
+            .field("max_period_span", &self.max_period_span)
+            .field("parallelism", &self.parallelism)
+            .field("chunking", &self.chunking)
+            .finish_non_exhaustive()
+    }
+}
+
+/** Async Mindenit API client.
+
+Async counterpart of [`Client`], available with the `async` feature.
+It shares the parsing code with the blocking client, so every method returns exactly the same data.
+
+# Examples
+```no_run
+# use nure_tools::Error;
+# use nure_tools::{
+#     groups::Group,
+#     schedule::{Lecture, Request},
+#     utils::Period,
+#     AsyncClient,
+# };
+# async fn run() -> Result<(), Error> {
+let client: AsyncClient = AsyncClient::builder().build_async()?;
+
+let group: Group = client.find_exect_group("пзпі-23-2").await?;
+let schedule: Vec<Lecture> = client
+    .get_schedule(Request::Group(group), Period::this_week())
+    .await?;
+println!("{:#?}", schedule);
+# Ok(())
+# }
+```
+**/
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncClient {
+    transport: Arc<dyn AsyncTransport>,
+    retry_policy: RetryPolicy,
+    pub(crate) directory: Arc<DirectoryCache>,
+    disk_cache: Option<DiskCache>,
+    pub(crate) lenient: bool,
+    pub(crate) resolve_lecture_rooms: bool,
+    pub(crate) time_zone: Tz,
+    pub(crate) max_period_span: Option<Duration>,
+    pub(crate) parallelism: usize,
+    pub(crate) chunking: Chunking,
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Create a new [`ClientBuilder`].
+     **/
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /** Helper function to send a Get request to `path` of the API and return its json body.
+
+    Async counterpart of [`Client::get_json`].
+    **/
+    pub async fn get_json(&self, path: &str, query: &[(&str, String)]) -> Result<Value> {
+        Ok(self.fetch_json(path, query).await?.data)
+    }
+
+    /** Same as [`get_json`], but also says where the json came from.
+
+    Async counterpart of [`Client::fetch_json`].
+
+    [`get_json`]: `AsyncClient::get_json`
+    **/
+    pub async fn fetch_json(&self, path: &str, query: &[(&str, String)]) -> Result<Fetched<Value>> {
+        let request = TransportRequest::new(path, query);
+        let (fresh, cached) = lookup_async(self.disk_cache.as_ref(), &request).await;
+        if let Some(fresh) = fresh {
+            return Ok(fresh);
+        }
+
+        let outcome = self.send(&request).await;
+        settle_async(self.disk_cache.as_ref(), &request, outcome, cached).await
+    }
+
+    async fn send(&self, request: &TransportRequest) -> Result<(TransportResponse, Value)> {
+        let mut attempt: u32 = 1;
+
+        loop {
+            let outcome = self.transport.get(request).await;
+            if self.retry_policy.should_retry(attempt, &outcome) {
+                tokio::time::sleep(self.retry_policy.delay(attempt, &outcome)).await;
+                attempt += 1;
+                continue;
+            }
+
+            return outcome
+                .and_then(|response| response.json().map(|value| (response, value)))
+                .map_err(|error| exhausted(error, attempt));
+        }
+    }
+
+    /** Download groups, teachers and lecture rooms lists again, replacing the cached ones.
+
+    Async counterpart of [`Client::refresh`].
+    **/
+    pub async fn refresh(&self) -> Result<()> {
+        self.refresh_groups().await?;
+        self.refresh_teachers().await?;
+        self.refresh_lecture_rooms().await?;
+        Ok(())
+    }
+
+    /** Drop the cached groups, teachers and lecture rooms lists.
+
+    Async counterpart of [`Client::invalidate`].
+    **/
+    pub fn invalidate(&self) {
+        self.directory.clear();
+    }
+}
+
+#[cfg(feature = "async")]
+impl fmt::Debug for AsyncClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncClient")
+            .field("retry_policy", &self.retry_policy)
+            .field("disk_cache", &self.disk_cache)
+            .field("lenient", &self.lenient)
+            .field("resolve_lecture_rooms", &self.resolve_lecture_rooms)
+            .field("time_zone", &self.time_zone)
+            .field("max_period_span", &self.max_period_span)
+            .field("parallelism", &self.parallelism)
+            .field("chunking", &self.chunking)
+            .finish_non_exhaustive()
+    }
+}
+
+/** Builder for a [`Client`] or an [`AsyncClient`].
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::ClientBuilder;
+use std::time::Duration;
+
+let client = ClientBuilder::new()
+    .base_url("http://localhost:8080/")
+    .header("X-Request-Source", "integration-tests")
+    .connect_timeout(Duration::from_secs(2))
+    .timeout(Duration::from_secs(5))
+    .build()?;
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Clone)]
+pub struct ClientBuilder {
+    base_url: String,
+    headers: Vec<(String, String)>,
+    user_agent: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    directory_ttl: Duration,
+    disk_cache: Option<DiskCache>,
+    lenient: bool,
+    resolve_lecture_rooms: bool,
+    time_zone: Tz,
+    max_period_span: Option<Duration>,
+    parallelism: usize,
+    chunking: Chunking,
+    #[cfg(feature = "blocking")]
+    transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "async")]
+    async_transport: Option<Arc<dyn AsyncTransport>>,
+}
+
+impl ClientBuilder {
+    /** Create a new builder pointing at [`DEFAULT_BASE_URL`].
+     **/
+    pub fn new() -> Self {
+        Self {
+            base_url: String::from(DEFAULT_BASE_URL),
+            headers: Vec::new(),
+            user_agent: None,
+            timeout: None,
+            connect_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            directory_ttl: DEFAULT_DIRECTORY_TTL,
+            disk_cache: None,
+            lenient: false,
+            resolve_lecture_rooms: false,
+            time_zone: DEFAULT_TIME_ZONE,
+            max_period_span: None,
+            parallelism: DEFAULT_PARALLELISM,
+            chunking: Chunking::Whole,
+            #[cfg(feature = "blocking")]
+            transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
+        }
+    }
+
+    /** Set the base URL, e.g. a staging mirror or a local stand-in server.
+
+    Trailing slashes are ignored.
+    **/
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /** Add a header sent with every request.
+     **/
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /** Set the `User-Agent` header.
+     **/
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /** Set the total timeout of a single request.
+     **/
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /** Set the timeout for establishing a connection.
+     **/
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /** Set the [`RetryPolicy`] applied to every request, [`RetryPolicy::default`] if not set.
+     **/
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /** Set how long the groups, teachers and lecture rooms lists are cached, [`DEFAULT_DIRECTORY_TTL`] if not set.
+
+    `Duration::ZERO` disables the cache.
+    **/
+    pub fn directory_ttl(mut self, directory_ttl: Duration) -> Self {
+        self.directory_ttl = directory_ttl;
+        self
+    }
+
+    /** Cache responses on disk with the given [`DiskCache`], no disk cache if not set.
+     **/
+    pub fn disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /** Skip records of API responses that can't be parsed instead of failing, disabled if not set.
+
+    Skipped records are returned in [`Fetched::warnings`] by the `fetch_*` methods.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::{errors::RecordError, transport::FixtureTransport, Client};
+    let transport = FixtureTransport::new().with_json(
+        "/lists/groups",
+        r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}, {"id": 10887036}]"#,
+    );
+
+    let strict = Client::builder().transport(transport.clone()).build()?;
+    assert!(strict.get_groups().is_err());
+
+    let lenient = Client::builder().transport(transport).lenient(true).build()?;
+    let groups = lenient.fetch_groups()?;
+    assert_eq!(groups.data.len(), 1);
+    assert_eq!(
+        groups.warnings,
+        vec![RecordError {
+            record: 1,
+            field: String::from("name"),
+            found: String::from("nothing"),
+        }]
+    );
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /** Resolve the room of every lecture returned by `get_schedule` into a [`LectureRoom`], disabled if not set.
+
+    Rooms are looked up in the lecture rooms list of the directory cache, downloading it if needed.
+    Lectures whose room is not in the list, or all of them if the list can't be downloaded, keep only the raw name.
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     schedule::{Lecture, Request},
+    #     transport::FixtureTransport,
+    #     utils::Period,
+    #     Client, Error,
+    # };
+    let lecture = r#"[{
+        "id": 1,
+        "startTime": 1704175200,
+        "endTime": 1704180900,
+        "auditory": "287",
+        "numberPair": 2,
+        "type": "Лк",
+        "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+        "teachers": [{"id": 1, "fullName": "Терещенко Гліб Юрійович", "shortName": "Терещенко Г. Ю."}],
+        "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+    }]"#;
+    let transport = FixtureTransport::new()
+        .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#)
+        .with_json("/lists/auditories", r#"[{"id": 51, "name": "287"}]"#)
+        .with_json("/schedule/groups/10887035", lecture)
+        .with_json("/schedule/auditories/51", lecture);
+    let client = Client::builder()
+        .transport(transport)
+        .resolve_lecture_rooms(true)
+        .build()?;
+
+    let period = Period::from_string("2024-01-02", "2024-01-03")?;
+    let group = client.find_exect_group("пзпі-23-2")?;
+    let schedule: Vec<Lecture> = client.get_schedule(Request::Group(group), period.clone())?;
+    assert_eq!(schedule[0].room.as_ref().map(|room| room.id), Some(51));
+
+    let request: Request = schedule[0].lecture_room_request().unwrap();
+    let room_schedule: Vec<Lecture> = client.get_schedule(request, period)?;
+    assert_eq!(room_schedule[0].lecture_room, "287");
+    # Ok::<(), Error>(())
+    ```
+
+    [`LectureRoom`]: `crate::lecture_rooms::LectureRoom`
+    **/
+    pub fn resolve_lecture_rooms(mut self, resolve_lecture_rooms: bool) -> Self {
+        self.resolve_lecture_rooms = resolve_lecture_rooms;
+        self
+    }
+
+    /** Set the time zone of the lecture periods returned by `get_schedule`, [`DEFAULT_TIME_ZONE`] if not set.
+
+    Only the way times are shown changes, requested periods may be in any time zone.
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     schedule::{Lecture, Request},
+    #     transport::FixtureTransport,
+    #     utils::Period,
+    #     Client, Error,
+    # };
+    use chrono_tz::Tz;
+
+    let transport = FixtureTransport::new()
+        .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#)
+        .with_json(
+            "/schedule/groups/10887035",
+            r#"[{
+                "id": 1,
+                "startTime": 1704180600,
+                "endTime": 1704186300,
+                "auditory": "287",
+                "numberPair": 2,
+                "type": "Лк",
+                "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+                "teachers": [],
+                "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+            }]"#,
+        );
+    let client = Client::builder()
+        .transport(transport)
+        .time_zone(Tz::UTC)
+        .build()?;
+
+    let group = client.find_exect_group("пзпі-23-2")?;
+    let schedule: Vec<Lecture> = client.get_schedule(Request::Group(group), Period::this_week())?;
+
+    assert_eq!(schedule[0].period.start_time.to_string(), "2024-01-02 07:30:00 UTC");
+    assert_eq!(
+        schedule[0].with_time_zone(Tz::Europe__Kiev).period.start_time.to_string(),
+        "2024-01-02 09:30:00 EET"
+    );
+    # Ok::<(), Error>(())
+    ```
+
+    [`DEFAULT_TIME_ZONE`]: `crate::utils::DEFAULT_TIME_ZONE`
+    **/
+    pub fn time_zone(mut self, time_zone: Tz) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /** Reject schedule requests for periods longer than `max_period_span` before sending them, no limit if not set.
+
+    Periods are always checked with [`Period::validate`], so inverted ones are never sent.
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     errors::ParseError,
+    #     groups::Group,
+    #     schedule::Request,
+    #     transport::FixtureTransport,
+    #     utils::Period,
+    #     Client, Error,
+    # };
+    use std::time::Duration;
+
+    let transport = FixtureTransport::new()
+        .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#);
+    let client = Client::builder()
+        .transport(transport.clone())
+        .max_period_span(Duration::from_secs(31 * 24 * 60 * 60))
+        .build()?;
+
+    let group: Group = client.find_exect_group("пзпі-23-2")?;
+    let year = Period::from_string("2024-01-01", "2024-12-31")?;
+
+    assert!(matches!(
+        client.get_schedule(Request::Group(group), year),
+        Err(Error::Parse(ParseError::PeriodTooLong(_, span))) if span == "31d"
+
+    ));
+    assert_eq!(transport.requests().len(), 1);
+    # Ok::<(), Error>(())
+    ```
+
+    [`Period::validate`]: `crate::utils::Period::validate`
+    **/
+    pub fn max_period_span(mut self, max_period_span: Duration) -> Self {
+        self.max_period_span = Some(max_period_span);
+        self
+    }
+
+    /** Set how many requests batch methods like `fetch_schedules` send at the same time, [`DEFAULT_PARALLELISM`] if not set.
+
+    Zero is treated as one.
+    **/
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /** Split the periods of `get_schedule` into chunks fetched separately, [`Chunking::Whole`] if not set.
+
+    Chunks are fetched [`ClientBuilder::parallelism`] at a time, the lectures are merged back in chronological order.
+
+    [`Chunking::Whole`]: `crate::batch::Chunking::Whole`
+    **/
+    pub fn chunking(mut self, chunking: Chunking) -> Self {
+        self.chunking = chunking;
+        self
+    }
+
+    /** Send every request of the [`Client`] through a custom [`Transport`].
+
+    Base URL, headers and timeouts are ignored by custom transports.
+    **/
+    #[cfg(feature = "blocking")]
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /** Send every request of the [`AsyncClient`] through a custom [`AsyncTransport`].
+
+    Base URL, headers and timeouts are ignored by custom transports.
+    **/
+    #[cfg(feature = "async")]
+    pub fn async_transport(mut self, transport: impl AsyncTransport + 'static) -> Self {
+        self.async_transport = Some(Arc::new(transport));
+        self
+    }
+
+    /** Build the [`Client`].
+
+    # Errors
+    This function fails if:
+     * [`RequestError::InvalidHeader`] - One of the given headers has an invalid name or value.
+     * [`RequestError::ClientBuildFailed`] - Underlying HTTP client can't be initialized.
+    **/
+    #[cfg(feature = "blocking")]
+    pub fn build(self) -> Result<Client> {
+        if let Some(transport) = self.transport {
+            return Ok(Client {
+                transport,
+                retry_policy: self.retry_policy,
+                directory: Arc::new(DirectoryCache::new(self.directory_ttl)),
+                disk_cache: self.disk_cache,
+                lenient: self.lenient,
+                resolve_lecture_rooms: self.resolve_lecture_rooms,
+                time_zone: self.time_zone,
+                max_period_span: self.max_period_span,
+                parallelism: self.parallelism,
+                chunking: self.chunking,
+            });
+        }
+
+        let mut http = blocking::Client::builder().default_headers(self.header_map()?);
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            http = http.connect_timeout(connect_timeout);
+        }
+
+        let http = match http.build() {
+            Ok(http) => http,
+            Err(_) => return Err(Error::from(RequestError::ClientBuildFailed)),
+        };
+
+        Ok(Client {
+            transport: Arc::new(ReqwestTransport::new(&self.base_url, http)),
+            retry_policy: self.retry_policy,
+            directory: Arc::new(DirectoryCache::new(self.directory_ttl)),
+            disk_cache: self.disk_cache,
+            lenient: self.lenient,
+            resolve_lecture_rooms: self.resolve_lecture_rooms,
+            time_zone: self.time_zone,
+            max_period_span: self.max_period_span,
+            parallelism: self.parallelism,
+            chunking: self.chunking,
+        })
+    }
+
+    /** Build the [`AsyncClient`].
+
+    # Errors
+    This function fails if:
+     * [`RequestError::InvalidHeader`] - One of the given headers has an invalid name or value.
+     * [`RequestError::ClientBuildFailed`] - Underlying HTTP client can't be initialized.
+    **/
+    #[cfg(feature = "async")]
+    pub fn build_async(self) -> Result<AsyncClient> {
+        if let Some(transport) = self.async_transport {
+            return Ok(AsyncClient {
+                transport,
+                retry_policy: self.retry_policy,
+                directory: Arc::new(DirectoryCache::new(self.directory_ttl)),
+                disk_cache: self.disk_cache,
+                lenient: self.lenient,
+                resolve_lecture_rooms: self.resolve_lecture_rooms,
+                time_zone: self.time_zone,
+                max_period_span: self.max_period_span,
+                parallelism: self.parallelism,
+                chunking: self.chunking,
+            });
+        }
+
+        let mut http = reqwest::Client::builder().default_headers(self.header_map()?);
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            http = http.connect_timeout(connect_timeout);
+        }
+
+        let http = match http.build() {
+            Ok(http) => http,
+            Err(_) => return Err(Error::from(RequestError::ClientBuildFailed)),
+        };
+
+        Ok(AsyncClient {
+            transport: Arc::new(AsyncReqwestTransport::new(&self.base_url, http)),
+            retry_policy: self.retry_policy,
+            directory: Arc::new(DirectoryCache::new(self.directory_ttl)),
+            disk_cache: self.disk_cache,
+            lenient: self.lenient,
+            resolve_lecture_rooms: self.resolve_lecture_rooms,
+            time_zone: self.time_zone,
+            max_period_span: self.max_period_span,
+            parallelism: self.parallelism,
+            chunking: self.chunking,
+        })
+    }
+
+    fn header_map(&self) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let header_name = match HeaderName::from_bytes(name.as_bytes()) {
+                Ok(header_name) => header_name,
+                Err(_) => return Err(Error::from(RequestError::InvalidHeader(name.clone()))),
+            };
+            let header_value = match HeaderValue::from_str(value) {
+                Ok(header_value) => header_value,
+                Err(_) => return Err(Error::from(RequestError::InvalidHeader(name.clone()))),
+            };
+            headers.append(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("base_url", &self.base_url)
+            .field("headers", &self.headers)
+            .field("user_agent", &self.user_agent)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("directory_ttl", &self.directory_ttl)
+            .field("disk_cache", &self.disk_cache)
+            .field("lenient", &self.lenient)
+            .field("resolve_lecture_rooms", &self.resolve_lecture_rooms)
+            .field("time_zone", &self.time_zone)
+            .field("max_period_span", &self.max_period_span)
+            .field("parallelism", &self.parallelism)
+            .field("chunking", &self.chunking)
+            .finish_non_exhaustive()
+    }
+}
+
+/** Client shared by the free functions of this crate.
+ **/
+#[cfg(feature = "blocking")]
+pub(crate) fn default_client() -> Result<&'static Client> {
+    static DEFAULT_CLIENT: OnceLock<Client> = OnceLock::new();
+
+    if let Some(client) = DEFAULT_CLIENT.get() {
+        return Ok(client);
+    }
+
+    let client = Client::builder().build()?;
+    Ok(DEFAULT_CLIENT.get_or_init(|| client))
+}
@@ -1,18 +1,116 @@
+use crate::retry::RETRYABLE_STATUSES;
+use std::time::Duration;
 use thiserror::Error;
 
+/// Result type returned by every fallible function of this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Underlying error of a library used by a transport, e.g. `reqwest::Error`.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/** Error returned by every fallible function of this crate.
+
+Underlying `reqwest` and `serde_json` errors are kept as the [`source`] of the error.
+
+# Variants
+ * `Request` - API answered with something unexpected, see [`RequestError`].
+ * `Find` - Nothing matches the search, see [`FindError`].
+ * `Parse` - Data can't be parsed, see [`ParseError`].
+ * `Transport` - Get request fails without any response.
+ * `Timeout` - Get request takes longer than the timeout of the client.
+ * `Decode` - Server returns value not in json format.
+ * `RateLimited` - Server returns `429 Too Many Requests`, with the delay it asked for.
+ * `AttemptsExhausted` - Request failed every attempt allowed by the [`RetryPolicy`], with the error of the last one.
+
+# Examples
+```
+# use nure_tools::{transport::FixtureTransport, Client, Error};
+let client = Client::builder().transport(FixtureTransport::new()).build()?;
+
+let error: Error = client.get_groups().unwrap_err();
+assert!(error.is_not_found());
+assert!(!error.is_retryable());
+# Ok::<(), Error>(())
+```
+
+[`source`]: `std::error::Error::source`
+[`RetryPolicy`]: `crate::retry::RetryPolicy`
+**/
 #[derive(Debug, Error)]
-pub enum RequestError {
+pub enum Error {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+
+    #[error(transparent)]
+    Find(#[from] FindError),
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
     #[error("Can't get any response")]
-    GetFailed,
+    Transport(#[source] BoxError),
+
+    #[error("Request timed out")]
+    Timeout(#[source] BoxError),
 
     #[error("Got respond not in json format")]
-    NotJson,
+    Decode(#[from] serde_json::Error),
+
+    #[error("API rate limit exceeded")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("Request failed after {attempts} attempts")]
+    AttemptsExhausted {
+        attempts: u32,
+        #[source]
+        last: Box<Error>,
+    },
+}
+
+impl Error {
+    /** Say if repeating the same call later may succeed.
 
+    True for transport errors, timeouts, rate limits and the status codes retried by the default [`RetryPolicy`].
+
+    [`RetryPolicy`]: `crate::retry::RetryPolicy`
+    **/
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport(_) | Self::Timeout(_) | Self::RateLimited { .. } => true,
+            Self::Request(RequestError::BadResponse(_, status)) => {
+                RETRYABLE_STATUSES.contains(status)
+            }
+            Self::AttemptsExhausted { last, .. } => last.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /** Say if the error means that the requested group, teacher, lecture room or endpoint doesn't exist.
+     **/
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::Find(FindError::InvalidRegexString(_)) => false,
+            Self::Find(_) => true,
+            Self::Request(RequestError::BadResponse(_, 404)) => true,
+            Self::AttemptsExhausted { last, .. } => last.is_not_found(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RequestError {
     #[error("API returned data in unexpexted format")]
     InvalidReturn,
 
     #[error("API returned with statuscode: {0} - {1}")]
     BadResponse(String, u16),
+
+    #[error("Can't use header: {0}")]
+    InvalidHeader(String),
+
+    #[error("Can't build http client")]
+    ClientBuildFailed,
 }
 
 #[derive(Debug, Error)]
@@ -37,4 +135,57 @@ pub enum ParseError {
 
     #[error("Can't parse DateTime from timestamp: {0}")]
     InvalidTimestampProvided(String),
+
+    #[error("API returned invalid record: {0}")]
+    InvalidRecord(RecordError),
+
+    #[error("Can't parse group code from string: {0}")]
+    InvalidGroupCode(String),
+
+    #[error("Can't parse teacher name from string: {0}")]
+    InvalidTeacherName(String),
+
+    #[error("Invalid bell schedule: {0}")]
+    InvalidBellSchedule(String),
+
+    #[error("Lecture of pair {0} doesn't match the bell schedule: {1}")]
+    PairMismatch(u8, String),
+
+    #[error("Period description is empty")]
+    EmptyPeriodDescription,
+
+    #[error("Can't understand `{0}` in period description")]
+    UnknownPeriodWord(String),
+
+    #[error("Period range has no end: {0}")]
+    IncompletePeriodRange(String),
+
+    #[error("Date doesn't exist: {0}")]
+    NonexistentDate(String),
+
+    #[error("Count is out of range: {0}")]
+    CountOutOfRange(String),
+
+    #[error("Period doesn't end after it starts: {0}")]
+    InvertedPeriod(String),
+
+    #[error("Period is longer than {1}: {0}")]
+    PeriodTooLong(String, String),
+
+    #[error("Year is out of the supported range: {0}")]
+    YearOutOfRange(i32),
+}
+
+/** Record of an API response that can't be turned into a data type.
+
+`record` is the index of the record in the response, `field` is the path to the offending field inside it
+(e.g. `teachers[0].shortName`, empty if the record itself is not an object),
+and `found` is the json found there (`nothing` if the field is missing).
+**/
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("record {record}, field `{field}`: found {found}")]
+pub struct RecordError {
+    pub record: usize,
+    pub field: String,
+    pub found: String,
 }
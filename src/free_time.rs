@@ -0,0 +1,588 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
+#[cfg(feature = "blocking")]
+use crate::Client;
+use crate::{
+    batch::BatchSchedule,
+    bells::BellSchedule,
+    calendar::DateRange,
+    errors::{Error, Result},
+    lecture_rooms::{filter_by_building, filter_by_floor, LectureRoom},
+    schedule::Request,
+    utils::{find, Period, PeriodSet, DEFAULT_TIME_ZONE},
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Tz;
+
+/** Constraints of a free time search.
+
+Defaults are: the whole day, no minimum length, any free time rather than whole pairs, weekends included,
+[`BellSchedule::nure`] in [`DEFAULT_TIME_ZONE`] for whole pairs.
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FreeTimeOptions {
+    working_hours: Option<(NaiveTime, NaiveTime)>,
+    min_duration: Duration,
+    whole_pairs: bool,
+    bells: BellSchedule,
+    bells_time_zone: Tz,
+    exclude_weekends: bool,
+}
+
+impl FreeTimeOptions {
+    /** Create new FreeTimeOptions with default settings.
+     **/
+    pub fn new() -> Self {
+        Self {
+            working_hours: None,
+            min_duration: Duration::zero(),
+            whole_pairs: false,
+            bells: BellSchedule::nure(),
+            bells_time_zone: DEFAULT_TIME_ZONE,
+            exclude_weekends: false,
+        }
+    }
+
+    /** Only look for free time between `start` and `end` of every day, in the time zone of the searched period.
+
+    Working hours ending before they start leave no free time.
+    **/
+    pub fn working_hours(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.working_hours = Some((start, end));
+        self
+    }
+
+    /** Drop free intervals shorter than `min_duration`.
+     **/
+    pub fn min_duration(mut self, min_duration: Duration) -> Self {
+        self.min_duration = min_duration;
+        self
+    }
+
+    /** Shrink free intervals to the whole pairs they contain, breaks between those pairs included.
+     **/
+    pub fn whole_pairs(mut self, whole_pairs: bool) -> Self {
+        self.whole_pairs = whole_pairs;
+        self
+    }
+
+    /** Set the [`BellSchedule`] used for whole pairs.
+     **/
+    pub fn bell_schedule(mut self, bells: BellSchedule) -> Self {
+        self.bells = bells;
+        self
+    }
+
+    /** Set the time zone the pairs of the [`BellSchedule`] are local times of.
+     **/
+    pub fn bells_time_zone(mut self, time_zone: Tz) -> Self {
+        self.bells_time_zone = time_zone;
+        self
+    }
+
+    /** Skip Saturdays and Sundays.
+     **/
+    pub fn exclude_weekends(mut self, exclude_weekends: bool) -> Self {
+        self.exclude_weekends = exclude_weekends;
+        self
+    }
+
+    fn window(&self, day: &Period) -> Option<Period> {
+        let date = day.start_time.date_naive();
+        if self.exclude_weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return None;
+        }
+
+        match self.working_hours {
+            Some((start, end)) => {
+                let time_zone = day.start_time.timezone();
+                day.intersection(&Period {
+                    start_time: at(date, start, time_zone)?,
+                    end_time: at(date, end, time_zone)?,
+                })
+            }
+            None => Some(day.clone()),
+        }
+    }
+
+    fn pairs_within(&self, free: &Period) -> Vec<Period> {
+        let time_zone = free.start_time.timezone();
+        let bells_time_zone = self.bells_time_zone;
+
+        free.with_time_zone(bells_time_zone)
+            .split_by_day()
+            .filter_map(|piece| {
+                let date = piece.start_time.date_naive();
+                let inside: Vec<Period> = self
+                    .bells
+                    .slots()
+                    .iter()
+                    .filter_map(|slot| {
+                        Some(Period {
+                            start_time: at(date, slot.start, bells_time_zone)?,
+                            end_time: at(date, slot.end, bells_time_zone)?,
+                        })
+                    })
+                    .filter(|slot| piece.contains_period(slot))
+                    .collect();
+
+                Some(Period {
+                    start_time: inside.first()?.start_time,
+                    end_time: inside.last()?.end_time,
+                })
+            })
+            .map(|pairs| pairs.with_time_zone(time_zone))
+            .collect()
+    }
+}
+
+impl Default for FreeTimeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/** Free intervals of `period` not covered by `busy`, ranked by length.
+
+Longest intervals come first, intervals of the same length in chronological order.
+Days are counted in the time zone of `period`.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::{
+#     free_time::{free_time, FreeTimeOptions},
+#     utils::{Period, PeriodSet},
+# };
+use chrono::{Duration, NaiveTime};
+
+let busy: PeriodSet = [
+    Period::from_string("2024-01-01T07:45:00+02:00", "2024-01-01T09:20:00+02:00")?,
+    Period::from_string("2024-01-01T13:10:00+02:00", "2024-01-01T14:45:00+02:00")?,
+]
+.into_iter()
+.collect();
+let period = Period::from_string("2024-01-01T00:00:00+02:00", "2024-01-02T00:00:00+02:00")?;
+let options = FreeTimeOptions::new()
+    .working_hours(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+    .min_duration(Duration::hours(1));
+
+let free: Vec<Period> = free_time(&busy, &period, &options);
+assert_eq!(free.len(), 2);
+assert_eq!(free[0].duration(), Duration::minutes(230));
+assert_eq!(free[1].start_time.to_rfc3339(), "2024-01-01T14:45:00+02:00");
+
+let pairs: Vec<Period> = free_time(&busy, &period, &options.whole_pairs(true));
+assert_eq!(pairs[0].start_time.to_rfc3339(), "2024-01-01T09:30:00+02:00");
+assert_eq!(pairs[0].end_time.to_rfc3339(), "2024-01-01T12:50:00+02:00");
+# Ok::<(), Error>(())
+```
+**/
+pub fn free_time(busy: &PeriodSet, period: &Period, options: &FreeTimeOptions) -> Vec<Period> {
+    let allowed: PeriodSet = period
+        .split_by_day()
+        .filter_map(|day| options.window(&day))
+        .collect();
+    let free: PeriodSet = allowed.difference(busy);
+
+    let mut slots: Vec<Period> = if options.whole_pairs {
+        free.iter()
+            .flat_map(|free| options.pairs_within(free))
+            .collect()
+    } else {
+        free.into_iter().collect()
+    };
+
+    slots.retain(|slot| !slot.is_empty() && slot.duration() >= options.min_duration);
+    slots.sort_by(|first, second| {
+        second
+            .duration()
+            .cmp(&first.duration())
+            .then(first.start_time.cmp(&second.start_time))
+    });
+    slots
+}
+
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Find the time within `period` when all of `requests` are free.
+
+    Schedules are fetched with [`Client::fetch_schedules`], the result is ranked as in [`free_time`].
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     free_time::FreeTimeOptions,
+    #     schedule::Request,
+    #     transport::FixtureTransport,
+    #     utils::Period,
+    #     Client, Error,
+    # };
+    use chrono::{Duration, NaiveTime};
+
+    let lecture = |start: i64, pair: u8| {
+        format!(
+            r#"[{{"startTime": {}, "endTime": {}, "auditory": "287", "numberPair": {}, "type": "Лк",
+                "groups": [], "teachers": [], "subject": {{"id": 2, "title": "ООП", "brief": "ООП"}}}}]"#,
+            start,
+            start + 5700,
+            pair
+        )
+    };
+    let transport = FixtureTransport::new()
+        .with_json(
+            "/lists/groups",
+            r#"[{"id": 10887034, "name": "ПЗПІ-23-1"}, {"id": 10887035, "name": "ПЗПІ-23-2"}]"#,
+        )
+        .with_json("/schedule/groups/10887034", lecture(1704087900, 1))
+        .with_json("/schedule/groups/10887035", lecture(1704094200, 2));
+    let client = Client::builder().transport(transport).build()?;
+
+    let requests: Vec<Request> = vec![
+        Request::Group(client.find_exect_group("пзпі-23-1")?),
+        Request::Group(client.find_exect_group("пзпі-23-2")?),
+    ];
+    // From Saturday to Tuesday.
+    let period = Period::from_string("2023-12-30T00:00:00+02:00", "2024-01-02T00:00:00+02:00")?;
+    let options = FreeTimeOptions::new()
+        .working_hours(NaiveTime::from_hms_opt(7, 45, 0).unwrap(), NaiveTime::from_hms_opt(21, 45, 0).unwrap())
+        .min_duration(Duration::minutes(30))
+        .exclude_weekends(true);
+
+    let (free, failed) = client.find_common_free_time(requests.clone(), period.clone(), &options);
+    assert!(failed.is_empty());
+    assert_eq!(free.len(), 1);
+    assert_eq!(free[0].start_time.to_rfc3339(), "2024-01-01T11:05:00+02:00");
+
+    let (pairs, _) = client.find_common_free_time(requests, period, &options.whole_pairs(true));
+    assert_eq!(pairs[0].start_time.to_rfc3339(), "2024-01-01T11:15:00+02:00");
+    assert_eq!(pairs[0].end_time.to_rfc3339(), "2024-01-01T21:45:00+02:00");
+    # Ok::<(), Error>(())
+    ```
+
+    Requests whose schedule can't be fetched are skipped and returned with their errors next to the free time,
+    which then only accounts for the other requests.
+    **/
+    pub fn find_common_free_time(
+        &self,
+        requests: Vec<Request>,
+        period: Period,
+        options: &FreeTimeOptions,
+    ) -> (Vec<Period>, Vec<(Request, Error)>) {
+        let mut batch = self.fetch_schedules(requests.clone(), period.clone());
+        let (busy, failed) = busy_time(&requests, &mut batch);
+
+        (free_time(&busy, &period, options), failed)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Find the time within `period` when all of `requests` are free.
+
+    Async counterpart of [`Client::find_common_free_time`].
+
+    [`Client::find_common_free_time`]: `crate::Client::find_common_free_time`
+    **/
+    pub async fn find_common_free_time(
+        &self,
+        requests: Vec<Request>,
+        period: Period,
+        options: &FreeTimeOptions,
+    ) -> (Vec<Period>, Vec<(Request, Error)>) {
+        let mut batch = self.fetch_schedules(requests.clone(), period.clone()).await;
+        let (busy, failed) = busy_time(&requests, &mut batch);
+
+        (free_time(&busy, &period, options), failed)
+    }
+}
+
+/** Which lecture rooms to search for free ones.
+
+By default every regular room is searched, remote and special locations are always skipped,
+see [`RoomLocation`].
+
+[`RoomLocation`]: `crate::lecture_rooms::RoomLocation`
+**/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomFilter {
+    building: Option<Option<String>>,
+    floor: Option<u32>,
+    name: Option<String>,
+}
+
+impl RoomFilter {
+    /** Create a new RoomFilter matching every regular room.
+     **/
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /** Only search rooms of the given building, `None` for the main building, see [`filter_by_building`].
+     **/
+    pub fn building(mut self, building: Option<&str>) -> Self {
+        self.building = Some(building.map(String::from));
+        self
+    }
+
+    /** Only search rooms on the given floor, see [`filter_by_floor`].
+     **/
+    pub fn floor(mut self, floor: u32) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /** Only search rooms whose name matches `name`, see [`find`].
+     **/
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    fn apply(&self, lecture_rooms: &[LectureRoom]) -> Result<Vec<LectureRoom>> {
+        let mut selected: Vec<LectureRoom> = lecture_rooms
+            .iter()
+            .filter(|lecture_room| lecture_room.location().is_room())
+            .cloned()
+            .collect();
+
+        if let Some(building) = &self.building {
+            selected = filter_by_building(&selected, building.as_deref());
+        }
+        if let Some(floor) = self.floor {
+            selected = filter_by_floor(&selected, floor);
+        }
+        if let Some(name) = &self.name {
+            let mut matched: Vec<LectureRoom> = Vec::new();
+            for lecture_room in selected {
+                if find(name, &lecture_room.name)? {
+                    matched.push(lecture_room);
+                }
+            }
+            selected = matched;
+        }
+
+        Ok(selected)
+    }
+}
+
+/** Lecture room without lectures during the searched period.
+
+`free` is the whole free interval around the searched period, from the end of the previous lecture
+to the start of the next one, bounded by the days of the searched period.
+**/
+#[derive(Debug, Clone)]
+pub struct FreeRoom {
+    pub lecture_room: LectureRoom,
+    pub free: Period,
+}
+
+/// Lecture rooms whose schedule couldn't be fetched during a free room search, with their errors.
+pub type FailedRooms = Vec<(LectureRoom, Error)>;
+
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Find the lecture rooms with no lectures during `period`.
+
+    Room schedules of the days of `period` are fetched with [`Client::fetch_schedules`], so they are cached
+    and fetched [`ClientBuilder::parallelism`] at a time.
+    Rooms that stay free longer come first, rooms staying free equally long are ordered by name.
+    A pair on a given date can be searched with [`BellSchedule::pair_period`].
+
+    # Examples
+    ```
+    # use nure_tools::{
+    #     bells::BellSchedule,
+    #     free_time::{FreeRoom, RoomFilter},
+    #     transport::FixtureTransport,
+    #     Client, Error,
+    # };
+    use chrono::NaiveDate;
+
+    let lecture = |start: i64, pair: u8| {
+        format!(
+            r#"[{{"startTime": {}, "endTime": {}, "auditory": "287", "numberPair": {}, "type": "Лк",
+                "groups": [], "teachers": [], "subject": {{"id": 2, "title": "ООП", "brief": "ООП"}}}}]"#,
+            start,
+            start + 5700,
+            pair
+        )
+    };
+    let transport = FixtureTransport::new()
+        .with_json(
+            "/lists/auditories",
+            r#"[
+                {"id": 1, "name": "287"},
+                {"id": 2, "name": "285"},
+                {"id": 3, "name": "і205"},
+                {"id": 4, "name": "ДИСТ"},
+                {"id": 5, "name": "290"}
+            ]"#,
+        )
+        .with_json("/schedule/auditories/1", lecture(1704186900, 3))
+        .with_json("/schedule/auditories/2", lecture(1704200100, 5))
+        .with_json("/schedule/auditories/3", "[]");
+    let client = Client::builder().transport(transport).build()?;
+
+    // Third pair on Tuesday.
+    let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    let pair = BellSchedule::nure().pair_period(date, 3).unwrap();
+
+    let (rooms, failed): (Vec<FreeRoom>, _) = client.find_free_rooms(pair.clone(), &RoomFilter::new())?;
+    assert_eq!(rooms.len(), 2);
+    // Room 290 has no schedule in the fixtures.
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].0.name, "290");
+    assert_eq!(rooms[0].lecture_room.name, "і205");
+    assert_eq!(rooms[1].free.end_time.to_rfc3339(), "2024-01-02T14:55:00+02:00");
+
+    let (main, _) = client.find_free_rooms(pair, &RoomFilter::new().building(None))?;
+    assert_eq!(main.len(), 1);
+    assert_eq!(main[0].lecture_room.name, "285");
+    # Ok::<(), Error>(())
+    ```
+
+    Rooms whose schedule can't be fetched are skipped and returned with their errors next to the free rooms.
+
+    # Errors
+    This function fails if:
+     * [`Client::get_lecture_rooms`] fails.
+     * [`find`] fails.
+
+    [`ClientBuilder::parallelism`]: `crate::ClientBuilder::parallelism`
+    **/
+    pub fn find_free_rooms(
+        &self,
+        period: Period,
+        filter: &RoomFilter,
+    ) -> Result<(Vec<FreeRoom>, FailedRooms)> {
+        let lecture_rooms = filter.apply(&self.get_lecture_rooms()?)?;
+        let requests: Vec<Request> = lecture_rooms
+            .iter()
+            .cloned()
+            .map(Request::LectureRoom)
+            .collect();
+
+        let window = days_of(&period);
+        let batch = self.fetch_schedules(requests.clone(), window.clone());
+
+        Ok(free_rooms(
+            lecture_rooms,
+            &requests,
+            batch,
+            &period,
+            &window,
+        ))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Find the lecture rooms with no lectures during `period`.
+
+    Async counterpart of [`Client::find_free_rooms`].
+
+    [`Client::find_free_rooms`]: `crate::Client::find_free_rooms`
+    **/
+    pub async fn find_free_rooms(
+        &self,
+        period: Period,
+        filter: &RoomFilter,
+    ) -> Result<(Vec<FreeRoom>, FailedRooms)> {
+        let lecture_rooms = filter.apply(&self.get_lecture_rooms().await?)?;
+        let requests: Vec<Request> = lecture_rooms
+            .iter()
+            .cloned()
+            .map(Request::LectureRoom)
+            .collect();
+
+        let window = days_of(&period);
+        let batch = self.fetch_schedules(requests.clone(), window.clone()).await;
+
+        Ok(free_rooms(
+            lecture_rooms,
+            &requests,
+            batch,
+            &period,
+            &window,
+        ))
+    }
+}
+
+/** Helper function to select the rooms free during `period` and find how long they stay free within `window`,
+along with the rooms whose schedule failed.
+**/
+fn free_rooms(
+    lecture_rooms: Vec<LectureRoom>,
+    requests: &[Request],
+    mut batch: BatchSchedule,
+    period: &Period,
+    window: &Period,
+) -> (Vec<FreeRoom>, FailedRooms) {
+    let mut rooms: Vec<FreeRoom> = Vec::new();
+    let mut failed: FailedRooms = Vec::new();
+
+    for (lecture_room, request) in lecture_rooms.into_iter().zip(requests) {
+        let (busy, errors) = busy_time(std::slice::from_ref(request), &mut batch);
+        if let Some((_, error)) = errors.into_iter().next() {
+            failed.push((lecture_room, error));
+            continue;
+        }
+
+        if let Some(free) = busy
+            .gaps(window)
+            .into_iter()
+            .find(|free| free.contains_period(period))
+        {
+            rooms.push(FreeRoom { lecture_room, free });
+        }
+    }
+
+    rooms.sort_by(|first, second| {
+        second
+            .free
+            .end_time
+            .cmp(&first.free.end_time)
+            .then_with(|| first.lecture_room.name.cmp(&second.lecture_room.name))
+    });
+    (rooms, failed)
+}
+
+/** Helper function to collect the lectures of `requests` into busy time, along with the requests that failed.
+**/
+pub(crate) fn busy_time(
+    requests: &[Request],
+    batch: &mut BatchSchedule,
+) -> (PeriodSet, Vec<(Request, Error)>) {
+    let mut busy = PeriodSet::new();
+    let mut failed: Vec<(Request, Error)> = Vec::new();
+
+    for request in requests {
+        match batch.results.remove(&request.key()) {
+            Some(Ok(fetched)) => {
+                busy.extend(fetched.data.into_iter().map(|lecture| lecture.period))
+            }
+            Some(Err(error)) => failed.push((request.clone(), error)),
+            None => {}
+        }
+    }
+
+    (busy, failed)
+}
+
+/** Helper function to extend `period` to the whole days it covers.
+
+The end is exclusive, so a period ending at midnight doesn't cover the day that starts then.
+**/
+fn days_of(period: &Period) -> Period {
+    let start = period.start_time.date_naive();
+    let end = (period.end_time - Duration::nanoseconds(1)).date_naive();
+
+    DateRange::new(start, end.max(start)).period_in(period.start_time.timezone())
+}
+
+fn at(date: NaiveDate, time: NaiveTime, time_zone: Tz) -> Option<DateTime<Tz>> {
+    time_zone
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+}
@@ -1,34 +1,149 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
 use crate::{
-    errors::{FindError, RequestError},
-    utils::{find, get_wrapper},
+    cache::{Fetched, ResponseSource},
+    errors::{Error, FindError, ParseError, RecordError, RequestError, Result},
+    utils::{find, DEFAULT_TIME_ZONE},
+    wire::{decode, decode_lenient, Decoded, GroupRecord},
 };
-use anyhow::{anyhow, Result};
-use reqwest::blocking::get;
+#[cfg(feature = "blocking")]
+use crate::{client::default_client, Client};
+use chrono::{Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
+use regex::Regex;
 use serde_json::Value;
+use std::sync::{Arc, OnceLock};
+
+/// Path of the groups list endpoint.
+pub(crate) const GROUPS_PATH: &str = "/lists/groups";
 
 /** Helper function to parse group json returned by API into [`Group`] struct.
 
 You probably will never use it, but you can if you want, see example in [`get_groups`] function source
+
+# Errors
+This function fails if:
+ * `ParseError::InvalidRecord` - Any of the records is not a valid group.
+**/
+pub fn parse_group_json(vector: Vec<Value>) -> Result<Vec<Group>> {
+    Ok(decode::<GroupRecord>(vector, false)?.items)
+}
+
+/** Helper function to parse group json returned by API into [`Group`] struct, skipping invalid records.
+
+Same as [`parse_group_json`], but returns the skipped records as warnings instead of failing.
+**/
+pub fn parse_group_json_lenient(vector: Vec<Value>) -> (Vec<Group>, Vec<RecordError>) {
+    let decoded = decode_lenient::<GroupRecord>(vector);
+    (decoded.items, decoded.warnings)
+}
+
+/** Helper function to turn the groups list endpoint response into `Vec<Group>`.
+
+In lenient mode invalid records are skipped and returned as warnings.
 **/
-pub fn parse_group_json(vector: Vec<Value>) -> Vec<Group> {
-    let mut result: Vec<Group> = Vec::new();
+pub(crate) fn groups_from_response(response: Value, lenient: bool) -> Result<Decoded<Group>> {
+    if let Value::Array(vector) = response {
+        decode::<GroupRecord>(vector, lenient)
+    } else {
+        Err(Error::from(RequestError::InvalidReturn))
+    }
+}
 
-    let mut id: i32 = 0;
-    let mut name: String = String::new();
+/** Helper function to select all groups that match `name`.
+**/
+pub(crate) fn filter_groups(groups: &[Group], name: &str) -> Result<Vec<Group>> {
+    let mut result: Vec<Group> = vec![];
 
-    for element in vector {
-        if let Value::Object(obj) = element {
-            if let Some(Value::Number(n)) = obj.get("id") {
-                id = n.as_i64().unwrap_or(0) as i32;
-            }
-            if let Some(Value::String(st)) = obj.get("name") {
-                name = st.clone();
-            }
-            result.push(Group::new(id, name.clone()));
-        };
+    for group in groups {
+        if find(name, &group.name)? {
+            result.push(group.clone());
+        } else {
+            continue;
+        }
     }
 
-    result
+    if result.is_empty() {
+        Err(Error::from(FindError::InvalidGroupName(String::from(name))))
+    } else {
+        Ok(result)
+    }
+}
+
+/** Helper function to select the group named exectly `name`.
+**/
+pub(crate) fn exect_group(groups: &[Group], name: &str) -> Result<Group> {
+    for group in groups {
+        if name.to_lowercase() == group.name.to_lowercase() {
+            return Ok(group.clone());
+        } else {
+            continue;
+        }
+    }
+
+    Err(Error::from(FindError::InvalidGroupName(String::from(name))))
+}
+
+/** Select all groups of the given program, e.g. `ПЗПІ`.
+
+Programs are compared case-insensitively, groups with unparsable names are skipped.
+
+# Examples
+```
+# use nure_tools::{
+#     groups::{filter_by_course, filter_by_program, Group},
+#     transport::FixtureTransport,
+#     Client, Error,
+# };
+use chrono::NaiveDate;
+
+# let transport = FixtureTransport::new().with_json(
+#     "/lists/groups",
+#     r#"[
+#         {"id": 1, "name": "ПЗПІ-23-2"},
+#         {"id": 2, "name": "ПЗПІ-22-1"},
+#         {"id": 3, "name": "КІУКІ-23-1"},
+#         {"id": 4, "name": "ПЗПІу-23-1"}
+#     ]"#,
+# );
+# let client = Client::builder().transport(transport).build()?;
+let groups: Vec<Group> = client.get_groups()?;
+let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+
+let second_course: Vec<Group> = filter_by_course(&filter_by_program(&groups, "пзпі"), 2, date);
+let names: Vec<&str> = second_course.iter().map(|group| group.name.as_str()).collect();
+assert_eq!(names, ["ПЗПІ-23-2", "ПЗПІу-23-1"]);
+# Ok::<(), Error>(())
+```
+**/
+pub fn filter_by_program(groups: &[Group], program: &str) -> Vec<Group> {
+    let program = program.to_lowercase();
+
+    groups
+        .iter()
+        .filter(|group| {
+            group
+                .code()
+                .is_some_and(|code| code.program.to_lowercase() == program)
+        })
+        .cloned()
+        .collect()
+}
+
+/** Select all groups studying at the given course on `date`.
+
+Groups with unparsable names are skipped, see [`GroupCode::course_on`].
+**/
+pub fn filter_by_course(groups: &[Group], course: u32, date: NaiveDate) -> Vec<Group> {
+    groups
+        .iter()
+        .filter(|group| {
+            group
+                .code()
+                .is_some_and(|code| code.course_on(date) == course)
+        })
+        .cloned()
+        .collect()
 }
 
 /** Get all existing groups.
@@ -38,7 +153,7 @@ Returns all existing groups in `Vec<Group>` format.
 # Examples
 ```
 # use nure_tools::groups::{get_groups, Group};
-# use anyhow::Error;
+# use nure_tools::Error;
 let groups: Vec<Group> = get_groups()?;
 println!("{:#?}", groups);
 # Ok::<(), Error>(())
@@ -46,19 +161,15 @@ println!("{:#?}", groups);
 
 # Errors
 This function fails if:
- * `RequestError::GetFailed` - Get request fails.
- * `RequestError::NotJson` - Server returns value not in json format.
+ * `Error::Transport` - Get request fails.
+ * `Error::Decode` - Server returns value not in json format.
  * `RequestError::BadResponse` - Server returns any response except 200.
  * `RequestError::InvalidReturn` - Server returns value in unexpected format.
+ * `ParseError::InvalidRecord` - Any of the returned groups is invalid.
 **/
+#[cfg(feature = "blocking")]
 pub fn get_groups() -> Result<Vec<Group>> {
-    let response = get_wrapper(get("https://api.mindenit.tech/lists/groups"))?;
-    if let Value::Array(vector) = response {
-        let result: Vec<Group> = parse_group_json(vector);
-        Ok(result)
-    } else {
-        Err(anyhow!(RequestError::InvalidReturn))
-    }
+    default_client()?.get_groups()
 }
 
 /** Find a group by it name.
@@ -71,7 +182,7 @@ Returns all matched groups in `Vec<Group>` format.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::groups::{find_group, Group};
 let group: Vec<Group> = find_group("пзпі-23-2")?;
 println!("groups: {:#?}\n", group);
@@ -87,23 +198,9 @@ This function fails if:
  * [`get_groups`] fails.
  * [`find`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_group(name: &str) -> Result<Vec<Group>> {
-    let groups = get_groups()?;
-    let mut result: Vec<Group> = vec![];
-
-    for group in groups {
-        if find(name, &group.name)? {
-            result.push(group);
-        } else {
-            continue;
-        }
-    }
-
-    if result.is_empty() {
-        Err(anyhow!(FindError::InvalidGroupName(String::from(name))))
-    } else {
-        Ok(result)
-    }
+    default_client()?.find_group(name)
 }
 
 /** Find exect group.
@@ -116,7 +213,7 @@ Returns 1 exect matched group.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::groups::{find_exect_group, Group};
 let group: Group = find_exect_group("пзпі-23-2")?;
 println!("group: {:#?}", group);
@@ -128,32 +225,137 @@ This function fails if:
  * `FindError::InvalidGroupName(name)` - There is no group that matches given name.
  * [`get_groups`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_exect_group(name: &str) -> Result<Group> {
-    let groups = get_groups()?;
+    default_client()?.find_exect_group(name)
+}
 
-    for group in groups {
-        if name.to_lowercase() == group.name.to_lowercase() {
-            return Ok(group);
-        } else {
-            continue;
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Get all existing groups.
+
+    Same as [`get_groups`], but uses this client.
+    The list is served from the directory cache of the client while it is fresh.
+    **/
+    pub fn get_groups(&self) -> Result<Vec<Group>> {
+        Ok(self.groups_directory()?.data.to_vec())
+    }
+
+    /** Get all existing groups and say where they came from.
+
+    Same as [`Client::get_groups`], but also returns the [`ResponseSource`].
+    **/
+    pub fn fetch_groups(&self) -> Result<Fetched<Vec<Group>>> {
+        let fetched = self.groups_directory()?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a group by it name.
+
+    Same as [`find_group`], but uses this client.
+    **/
+    pub fn find_group(&self, name: &str) -> Result<Vec<Group>> {
+        filter_groups(&self.groups_directory()?.data, name)
+    }
+
+    /** Find exect group.
+
+    Same as [`find_exect_group`], but uses this client.
+    **/
+    pub fn find_exect_group(&self, name: &str) -> Result<Group> {
+        exect_group(&self.groups_directory()?.data, name)
+    }
+
+    pub(crate) fn groups_directory(&self) -> Result<Fetched<Arc<Vec<Group>>>> {
+        match self.directory.groups.get() {
+            Some(groups) => Ok(Fetched::new(groups, ResponseSource::FreshCache)),
+            None => self.refresh_groups(),
         }
     }
 
-    Err(anyhow!(FindError::InvalidGroupName(String::from(name))))
+    pub(crate) fn refresh_groups(&self) -> Result<Fetched<Arc<Vec<Group>>>> {
+        let fetched = self.fetch_json(GROUPS_PATH, &[])?;
+        let decoded = groups_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory.groups.set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Get all existing groups.
+
+    Async counterpart of [`Client::get_groups`].
+    **/
+    pub async fn get_groups(&self) -> Result<Vec<Group>> {
+        Ok(self.groups_directory().await?.data.to_vec())
+    }
+
+    /** Get all existing groups and say where they came from.
+
+    Async counterpart of [`Client::fetch_groups`].
+    **/
+    pub async fn fetch_groups(&self) -> Result<Fetched<Vec<Group>>> {
+        let fetched = self.groups_directory().await?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a group by it name.
+
+    Async counterpart of [`Client::find_group`].
+    **/
+    pub async fn find_group(&self, name: &str) -> Result<Vec<Group>> {
+        filter_groups(&self.groups_directory().await?.data, name)
+    }
+
+    /** Find exect group.
+
+    Async counterpart of [`Client::find_exect_group`].
+    **/
+    pub async fn find_exect_group(&self, name: &str) -> Result<Group> {
+        exect_group(&self.groups_directory().await?.data, name)
+    }
+
+    pub(crate) async fn groups_directory(&self) -> Result<Fetched<Arc<Vec<Group>>>> {
+        match self.directory.groups.get() {
+            Some(groups) => Ok(Fetched::new(groups, ResponseSource::FreshCache)),
+            None => self.refresh_groups().await,
+        }
+    }
+
+    pub(crate) async fn refresh_groups(&self) -> Result<Fetched<Arc<Vec<Group>>>> {
+        let fetched = self.fetch_json(GROUPS_PATH, &[]).await?;
+        let decoded = groups_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory.groups.set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
 }
 
 /** Group struct.
 **/
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     pub id: i32,
     pub name: String,
 }
 
 impl Group {
-    fn new(id: i32, name: String) -> Self {
+    pub(crate) fn new(id: i32, name: String) -> Self {
         Self { id, name }
     }
+
+    /** [`GroupCode`] parsed from the name of the group, if it has the usual format.
+     **/
+    pub fn code(&self) -> Option<GroupCode> {
+        self.name.parse().ok()
+    }
 }
 
 impl std::fmt::Display for Group {
@@ -161,3 +363,100 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.name)
     }
 }
+
+/** Parsed group name, e.g. `ПЗПІ-23-2` or `ПЗПІу-23-1`.
+
+A group name consists of the program abbreviation in capital letters,
+an optional suffix in lowercase letters (e.g. `у` for shortened programs or `и` for foreign students),
+the last two digits of the admission year and the group number, separated by dashes.
+
+# Examples
+```
+# use nure_tools::{groups::GroupCode, Error};
+use chrono::NaiveDate;
+
+let code: GroupCode = "ПЗПІу-23-2".parse()?;
+
+assert_eq!(code.program, "ПЗПІ");
+assert_eq!(code.suffix.as_deref(), Some("у"));
+assert_eq!(code.admission_year, 2023);
+assert_eq!(code.number, 2);
+assert_eq!(code.course_on(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()), 1);
+assert_eq!(code.course_on(NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()), 2);
+assert_eq!(code.to_string(), "ПЗПІу-23-2");
+# Ok::<(), Error>(())
+```
+
+# Errors
+Parsing fails with [`ParseError::InvalidGroupCode`] if the string doesn't have the format described above.
+
+[`ParseError::InvalidGroupCode`]: `crate::errors::ParseError::InvalidGroupCode`
+**/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupCode {
+    pub program: String,
+    pub suffix: Option<String>,
+    pub admission_year: i32,
+    pub number: u32,
+}
+
+impl GroupCode {
+    /** Course the group studies at on `date`.
+
+    Academic year starts on the 1st of September, so the group is at course 1 from its admission until the next September.
+    Returns 0 before the admission.
+    **/
+    pub fn course_on(&self, date: NaiveDate) -> u32 {
+        let years = date.year() - self.admission_year;
+        let course = if date.month() >= 9 { years + 1 } else { years };
+
+        course.max(0) as u32
+    }
+
+    /** Course the group studies at today, in Kyiv time.
+     **/
+    pub fn current_course(&self) -> u32 {
+        self.current_course_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`GroupCode::current_course`], but for the current day in `time_zone`.
+     **/
+    pub fn current_course_in(&self, time_zone: Tz) -> u32 {
+        self.course_on(Utc::now().with_timezone(&time_zone).date_naive())
+    }
+}
+
+impl std::str::FromStr for GroupCode {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        static GROUP_CODE: OnceLock<Regex> = OnceLock::new();
+        let regex = GROUP_CODE.get_or_init(|| {
+            Regex::new(r"^(\p{Lu}+)(\p{Ll}*)-(\d{2})-(\d+)$").expect("group code regex is valid")
+        });
+
+        let invalid = || Error::from(ParseError::InvalidGroupCode(String::from(name)));
+        let captures = regex.captures(name.trim()).ok_or_else(invalid)?;
+
+        Ok(Self {
+            program: String::from(&captures[1]),
+            suffix: Some(String::from(&captures[2])).filter(|suffix| !suffix.is_empty()),
+            admission_year: 2000 + captures[3].parse::<i32>().map_err(|_| invalid())?,
+            number: captures[4].parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl std::fmt::Display for GroupCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}-{:02}-{}",
+            self.program,
+            self.suffix.as_deref().unwrap_or(""),
+            self.admission_year % 100,
+            self.number
+        )
+    }
+}
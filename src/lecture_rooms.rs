@@ -1,36 +1,156 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
 use crate::{
-    errors::{FindError, RequestError},
-    utils::{find, get_wrapper},
+    cache::{Fetched, ResponseSource},
+    errors::{Error, FindError, RecordError, RequestError, Result},
+    utils::find,
+    wire::{decode, decode_lenient, Decoded, LectureRoomRecord},
 };
-use anyhow::{anyhow, Result};
-use reqwest::blocking::get;
+#[cfg(feature = "blocking")]
+use crate::{client::default_client, Client};
+use regex::Regex;
 use serde_json::{self, Value};
+use std::sync::{Arc, OnceLock};
+
+/// Path of the lecture rooms list endpoint.
+pub(crate) const LECTURE_ROOMS_PATH: &str = "/lists/auditories";
 
 /** Helper function to parse lecture_room json returned by API into [`LectureRoom`] struct.
 
 You probably will never use it, but you can if you want, see example in [`get_lecture_rooms`] function source
+
+# Errors
+This function fails if:
+ * `ParseError::InvalidRecord` - Any of the records is not a valid lecture room.
+**/
+pub fn parse_lecture_room_json(vector: Vec<Value>) -> Result<Vec<LectureRoom>> {
+    Ok(decode::<LectureRoomRecord>(vector, false)?.items)
+}
+
+/** Helper function to parse lecture_room json returned by API into [`LectureRoom`] struct, skipping invalid records.
+
+Same as [`parse_lecture_room_json`], but returns the skipped records as warnings instead of failing.
+**/
+pub fn parse_lecture_room_json_lenient(vector: Vec<Value>) -> (Vec<LectureRoom>, Vec<RecordError>) {
+    let decoded = decode_lenient::<LectureRoomRecord>(vector);
+    (decoded.items, decoded.warnings)
+}
+
+/** Helper function to turn the lecture rooms list endpoint response into `Vec<LectureRoom>`.
+
+In lenient mode invalid records are skipped and returned as warnings.
 **/
-pub fn parse_lecture_room_json(vector: Vec<Value>) -> Vec<LectureRoom> {
-    let mut result: Vec<LectureRoom> = Vec::new();
+pub(crate) fn lecture_rooms_from_response(
+    response: Value,
+    lenient: bool,
+) -> Result<Decoded<LectureRoom>> {
+    if let Value::Array(vector) = response {
+        decode::<LectureRoomRecord>(vector, lenient)
+    } else {
+        Err(Error::from(RequestError::InvalidReturn))
+    }
+}
 
-    let mut id: i32 = 0;
-    let mut name: String = String::new();
+/** Helper function to select all lecture_rooms that match `name`.
+**/
+pub(crate) fn filter_lecture_rooms(
+    lecture_rooms: &[LectureRoom],
+    name: &str,
+) -> Result<Vec<LectureRoom>> {
+    let mut result: Vec<LectureRoom> = vec![];
 
-    for element in vector {
-        if let Value::Object(obj) = element {
-            if let Some(Value::Number(n)) = obj.get("id") {
-                id = n.as_i64().unwrap_or(0) as i32;
-            }
+    for lecture_room in lecture_rooms {
+        if find(name, &lecture_room.name)? {
+            result.push(lecture_room.clone());
+        } else {
+            continue;
+        }
+    }
 
-            if let Some(Value::String(st)) = obj.get("name") {
-                name = st.clone();
-            }
+    if result.is_empty() {
+        Err(Error::from(FindError::InvalidLectureRoomName(
+            String::from(name),
+        )))
+    } else {
+        Ok(result)
+    }
+}
 
-            result.push(LectureRoom::new(id, name.clone()));
-        };
+/** Helper function to select the lecture_room named exectly `name`.
+**/
+pub(crate) fn exect_lecture_room(lecture_rooms: &[LectureRoom], name: &str) -> Result<LectureRoom> {
+    for lecture_rooms in lecture_rooms {
+        if name.to_lowercase() == lecture_rooms.name.to_lowercase() {
+            return Ok(lecture_rooms.clone());
+        } else {
+            continue;
+        }
     }
 
-    result
+    Err(Error::from(FindError::InvalidGroupName(String::from(name))))
+}
+
+/** Select all lecture rooms of the given building, `None` for the main building.
+
+Buildings are compared case-insensitively, special and remote locations are skipped.
+
+# Examples
+```
+# use nure_tools::{
+#     lecture_rooms::{filter_by_building, filter_by_floor, LectureRoom},
+#     transport::FixtureTransport,
+#     Client, Error,
+# };
+# let transport = FixtureTransport::new().with_json(
+#     "/lists/auditories",
+#     r#"[
+#         {"id": 1, "name": "287"},
+#         {"id": 2, "name": "і205"},
+#         {"id": 3, "name": "і312"},
+#         {"id": 4, "name": "ФІЛІЯ"},
+#         {"id": 5, "name": "И201"}
+#     ]"#,
+# );
+# let client = Client::builder().transport(transport).build()?;
+let lecture_rooms: Vec<LectureRoom> = client.get_lecture_rooms()?;
+
+let building: Vec<LectureRoom> = filter_by_building(&lecture_rooms, Some("І"));
+assert_eq!(building.len(), 2);
+
+let floor: Vec<LectureRoom> = filter_by_floor(&filter_by_building(&lecture_rooms, None), 2);
+assert_eq!(floor[0].name, "287");
+# Ok::<(), Error>(())
+```
+**/
+pub fn filter_by_building(
+    lecture_rooms: &[LectureRoom],
+    building: Option<&str>,
+) -> Vec<LectureRoom> {
+    let building = building.map(str::to_lowercase);
+
+    lecture_rooms
+        .iter()
+        .filter(|lecture_room| match lecture_room.location() {
+            RoomLocation::Room {
+                building: room_building,
+                ..
+            } => room_building.map(|room_building| room_building.to_lowercase()) == building,
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+/** Select all lecture rooms on the given floor of any building.
+
+Special and remote locations and rooms without a known floor are skipped.
+**/
+pub fn filter_by_floor(lecture_rooms: &[LectureRoom], floor: u32) -> Vec<LectureRoom> {
+    lecture_rooms
+        .iter()
+        .filter(|lecture_room| lecture_room.location().floor() == Some(floor))
+        .cloned()
+        .collect()
 }
 
 /** Get all existing lecture_rooms
@@ -40,7 +160,7 @@ Returns all existing lecture rooms in `Vec<LectureRoom>` format.
 # Examples
 ```
 # use nure_tools::lecture_rooms::{get_lecture_rooms, LectureRoom};
-# use anyhow::Error;
+# use nure_tools::Error;
 let lecture_rooms: Vec<LectureRoom> = get_lecture_rooms()?;
 println!("{:#?}", lecture_rooms);
 # Ok::<(), Error>(())
@@ -48,19 +168,15 @@ println!("{:#?}", lecture_rooms);
 
 # Errors
 This function fails if:
- * `RequestError::GetFailed` - Get request fails.
- * `RequestError::NotJson` - Server returns value not in json format.
+ * `Error::Transport` - Get request fails.
+ * `Error::Decode` - Server returns value not in json format.
  * `RequestError::BadResponse` - Server returns any response except 200.
  * `RequestError::InvalidReturn` - Server returns value in unexpected format.
+ * `ParseError::InvalidRecord` - Any of the returned lecture rooms is invalid.
 **/
+#[cfg(feature = "blocking")]
 pub fn get_lecture_rooms() -> Result<Vec<LectureRoom>> {
-    let response = get_wrapper(get("https://api.mindenit.tech/lists/auditories"))?;
-    if let Value::Array(vector) = response {
-        let result: Vec<LectureRoom> = parse_lecture_room_json(vector);
-        Ok(result)
-    } else {
-        Err(anyhow!(RequestError::InvalidReturn))
-    }
+    default_client()?.get_lecture_rooms()
 }
 
 /** Find a lecture_room by it name
@@ -73,7 +189,7 @@ Returns all matched lecture_rooms in `Vec<LectureRoom>` format.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::lecture_rooms::{find_lecture_room, LectureRoom};
 let lecture_room: Vec<LectureRoom> = find_lecture_room("і")?;
 println!("lecture_rooms: {:#?}\n", lecture_room);
@@ -89,25 +205,9 @@ This function fails if:
  * [`get_lecture_rooms`] fails.
  * [`find`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_lecture_room(name: &str) -> Result<Vec<LectureRoom>> {
-    let lecture_rooms = get_lecture_rooms()?;
-    let mut result: Vec<LectureRoom> = vec![];
-
-    for lecture_room in lecture_rooms {
-        if find(name, &lecture_room.name)? {
-            result.push(lecture_room);
-        } else {
-            continue;
-        }
-    }
-
-    if result.is_empty() {
-        Err(anyhow!(FindError::InvalidLectureRoomName(String::from(
-            name
-        ))))
-    } else {
-        Ok(result)
-    }
+    default_client()?.find_lecture_room(name)
 }
 
 /** Find exect lecture_room.
@@ -120,7 +220,7 @@ Returns 1 exect matched lecture_room.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::lecture_rooms::{find_exect_lecture_room, LectureRoom};
 let lecture_room: LectureRoom = find_exect_lecture_room("ФІЛІЯ")?;
 println!("lecture_room: {:#?}", lecture_room);
@@ -132,32 +232,141 @@ This function fails if:
  * `FindError::InvalidLectureRoomName(name)` - There is no lecture_room that matches given name.
  * [`get_lecture_rooms`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_exect_lecture_room(name: &str) -> Result<LectureRoom> {
-    let lecture_rooms = get_lecture_rooms()?;
+    default_client()?.find_exect_lecture_room(name)
+}
 
-    for lecture_rooms in lecture_rooms {
-        if name.to_lowercase() == lecture_rooms.name.to_lowercase() {
-            return Ok(lecture_rooms);
-        } else {
-            continue;
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Get all existing lecture_rooms.
+
+    Same as [`get_lecture_rooms`], but uses this client.
+    The list is served from the directory cache of the client while it is fresh.
+    **/
+    pub fn get_lecture_rooms(&self) -> Result<Vec<LectureRoom>> {
+        Ok(self.lecture_rooms_directory()?.data.to_vec())
+    }
+
+    /** Get all existing lecture_rooms and say where they came from.
+
+    Same as [`Client::get_lecture_rooms`], but also returns the [`ResponseSource`].
+    **/
+    pub fn fetch_lecture_rooms(&self) -> Result<Fetched<Vec<LectureRoom>>> {
+        let fetched = self.lecture_rooms_directory()?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a lecture_room by it name.
+
+    Same as [`find_lecture_room`], but uses this client.
+    **/
+    pub fn find_lecture_room(&self, name: &str) -> Result<Vec<LectureRoom>> {
+        filter_lecture_rooms(&self.lecture_rooms_directory()?.data, name)
+    }
+
+    /** Find exect lecture_room.
+
+    Same as [`find_exect_lecture_room`], but uses this client.
+    **/
+    pub fn find_exect_lecture_room(&self, name: &str) -> Result<LectureRoom> {
+        exect_lecture_room(&self.lecture_rooms_directory()?.data, name)
+    }
+
+    pub(crate) fn lecture_rooms_directory(&self) -> Result<Fetched<Arc<Vec<LectureRoom>>>> {
+        match self.directory.lecture_rooms.get() {
+            Some(lecture_rooms) => Ok(Fetched::new(lecture_rooms, ResponseSource::FreshCache)),
+            None => self.refresh_lecture_rooms(),
+        }
+    }
+
+    pub(crate) fn refresh_lecture_rooms(&self) -> Result<Fetched<Arc<Vec<LectureRoom>>>> {
+        let fetched = self.fetch_json(LECTURE_ROOMS_PATH, &[])?;
+        let decoded = lecture_rooms_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory
+                .lecture_rooms
+                .set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Get all existing lecture_rooms.
+
+    Async counterpart of [`Client::get_lecture_rooms`].
+    **/
+    pub async fn get_lecture_rooms(&self) -> Result<Vec<LectureRoom>> {
+        Ok(self.lecture_rooms_directory().await?.data.to_vec())
+    }
+
+    /** Get all existing lecture_rooms and say where they came from.
+
+    Async counterpart of [`Client::fetch_lecture_rooms`].
+    **/
+    pub async fn fetch_lecture_rooms(&self) -> Result<Fetched<Vec<LectureRoom>>> {
+        let fetched = self.lecture_rooms_directory().await?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a lecture_room by it name.
+
+    Async counterpart of [`Client::find_lecture_room`].
+    **/
+    pub async fn find_lecture_room(&self, name: &str) -> Result<Vec<LectureRoom>> {
+        filter_lecture_rooms(&self.lecture_rooms_directory().await?.data, name)
+    }
+
+    /** Find exect lecture_room.
+
+    Async counterpart of [`Client::find_exect_lecture_room`].
+    **/
+    pub async fn find_exect_lecture_room(&self, name: &str) -> Result<LectureRoom> {
+        exect_lecture_room(&self.lecture_rooms_directory().await?.data, name)
+    }
+
+    pub(crate) async fn lecture_rooms_directory(&self) -> Result<Fetched<Arc<Vec<LectureRoom>>>> {
+        match self.directory.lecture_rooms.get() {
+            Some(lecture_rooms) => Ok(Fetched::new(lecture_rooms, ResponseSource::FreshCache)),
+            None => self.refresh_lecture_rooms().await,
         }
     }
 
-    Err(anyhow!(FindError::InvalidGroupName(String::from(name))))
+    pub(crate) async fn refresh_lecture_rooms(&self) -> Result<Fetched<Arc<Vec<LectureRoom>>>> {
+        let fetched = self.fetch_json(LECTURE_ROOMS_PATH, &[]).await?;
+        let decoded = lecture_rooms_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory
+                .lecture_rooms
+                .set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
 }
 
 /** LectureRoom struct.
 **/
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LectureRoom {
     pub id: i32,
     pub name: String,
 }
 
 impl LectureRoom {
-    fn new(id: i32, name: String) -> Self {
+    pub(crate) fn new(id: i32, name: String) -> Self {
         Self { id, name }
     }
+
+    /** [`RoomLocation`] parsed from the name of the lecture room.
+     **/
+    pub fn location(&self) -> RoomLocation {
+        RoomLocation::from(self.name.as_str())
+    }
 }
 
 impl std::fmt::Display for LectureRoom {
@@ -165,3 +374,127 @@ impl std::fmt::Display for LectureRoom {
         write!(f, "{}", self.name)
     }
 }
+
+/** Location of a lecture room parsed from its name, e.g. `287`, `і205` or `ФІЛІЯ`.
+
+A room name is an optional building (wing) prefix of letters, followed by the room number with an optional letter suffix.
+All digits of the number except the last two are the floor, so `287` is on the 2nd floor of the main building
+and `і205` on the 2nd floor of building `і`.
+Names mentioning distance learning (`дист`, `онлайн`, `online`, `zoom`, `meet`) are remote, anything else is special.
+
+# Variants
+ * `Room` - regular room, `building` is `None` for the main building, `floor` is `None` for one or two digit numbers.
+ * `Remote` - remote lecture.
+ * `Special` - any other location, with the name as is.
+
+# Examples
+```
+# use nure_tools::lecture_rooms::RoomLocation;
+let location = RoomLocation::from("і205а");
+
+assert_eq!(location.building(), Some("і"));
+assert_eq!(location.floor(), Some(2));
+assert_eq!(
+    location,
+    RoomLocation::Room {
+        building: Some(String::from("і")),
+        floor: Some(2),
+        number: String::from("205а"),
+    }
+);
+
+assert_eq!(RoomLocation::from("287").building(), None);
+assert_eq!(RoomLocation::from("ДИСТ"), RoomLocation::Remote);
+assert_eq!(RoomLocation::from("дист-1"), RoomLocation::Remote);
+assert_eq!(RoomLocation::from("２８７"), RoomLocation::Special(String::from("２８７")));
+assert_eq!(RoomLocation::from("ФІЛІЯ"), RoomLocation::Special(String::from("ФІЛІЯ")));
+```
+**/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoomLocation {
+    Room {
+        building: Option<String>,
+        floor: Option<u32>,
+        number: String,
+    },
+    Remote,
+    Special(String),
+}
+
+impl RoomLocation {
+    /** Building of the room, `None` for the main building and non-room locations.
+     **/
+    pub fn building(&self) -> Option<&str> {
+        match self {
+            Self::Room { building, .. } => building.as_deref(),
+            _ => None,
+        }
+    }
+
+    /** Floor of the room, if known.
+     **/
+    pub fn floor(&self) -> Option<u32> {
+        match self {
+            Self::Room { floor, .. } => *floor,
+            _ => None,
+        }
+    }
+
+    /** Say if it is a regular room.
+     **/
+    pub fn is_room(&self) -> bool {
+        matches!(self, Self::Room { .. })
+    }
+
+    /** Say if the lecture is remote.
+     **/
+    pub fn is_remote(&self) -> bool {
+        *self == Self::Remote
+    }
+}
+
+impl From<&str> for RoomLocation {
+    fn from(name: &str) -> Self {
+        static ROOM: OnceLock<Regex> = OnceLock::new();
+        let regex = ROOM.get_or_init(|| {
+            Regex::new(r"^(\p{L}*)[\s-]?(([0-9]+)\p{L}?)$").expect("room regex is valid")
+        });
+
+        let trimmed = name.trim();
+        let lowercase = trimmed.to_lowercase();
+        if ["дист", "онлайн", "online", "zoom", "meet"]
+            .iter()
+            .any(|remote| lowercase.contains(remote))
+        {
+            return Self::Remote;
+        }
+
+        if let Some(captures) = regex.captures(trimmed) {
+            let digits = &captures[3];
+
+            return Self::Room {
+                building: Some(String::from(&captures[1])).filter(|building| !building.is_empty()),
+                floor: match digits.len() {
+                    0..=2 => None,
+                    length => digits[..length - 2].parse().ok(),
+                },
+                number: String::from(&captures[2]),
+            };
+        }
+
+        Self::Special(String::from(trimmed))
+    }
+}
+
+impl std::fmt::Display for RoomLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Room {
+                building, number, ..
+            } => write!(f, "{}{}", building.as_deref().unwrap_or(""), number),
+            Self::Remote => write!(f, "Дистанційно"),
+            Self::Special(name) => write!(f, "{}", name),
+        }
+    }
+}
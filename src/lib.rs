@@ -1,6 +1,26 @@
 //! # nure_tools
 //!
-//! `nure_tools` is a crate to synchronously interact with Mindenit API.
+//! `nure_tools` is a crate to synchronously or asynchronously interact with Mindenit API.
+//!
+//! # Features
+//!  * `blocking` (enabled by default) - blocking [`Client`] and the free functions of every module.
+//!  * `async` - [`AsyncClient`] with async counterparts of every fetch and find function, requires a tokio runtime with time enabled.
+//!  * `serde` - `Serialize` and `Deserialize` for every public data type, see [`schedule::Lecture`] and [`utils::Period`].
+//!
+//! At least one of them has to be enabled.
+
+#[cfg(not(any(feature = "blocking", feature = "async")))]
+compile_error!("nure_tools requires at least one of the `blocking` or `async` features");
+
+/**
+Mindenit API client.
+*/
+pub mod client;
+#[cfg(feature = "async")]
+pub use client::AsyncClient;
+#[cfg(feature = "blocking")]
+pub use client::Client;
+pub use client::ClientBuilder;
 
 /**
 Groups related functions.
@@ -17,6 +37,16 @@ Schedule related functions.
 */
 pub mod schedule;
 
+/**
+Batch and chunked schedule fetching.
+*/
+pub mod batch;
+
+/**
+Common free time and free lecture room search.
+*/
+pub mod free_time;
+
 /**
 Teachers related functions.
 */
@@ -27,7 +57,40 @@ Usefull utilities.
 */
 pub mod utils;
 
+/**
+University bell schedule.
+*/
+pub mod bells;
+
+/**
+Academic calendar: semesters, exam sessions and holidays.
+*/
+pub mod calendar;
+
+/**
+Natural-language period parsing.
+*/
+pub mod natural;
+
 /**
 Errors enums implementation.
 */
 pub mod errors;
+pub use errors::{Error, Result};
+
+/**
+Pluggable HTTP transports.
+*/
+pub mod transport;
+
+/**
+Retry policy for failed requests.
+*/
+pub mod retry;
+
+/**
+Caches used by the clients.
+*/
+pub mod cache;
+
+mod wire;
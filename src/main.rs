@@ -1,8 +1,8 @@
-use anyhow::Error;
 use nure_tools::{
     groups::{find_group, Group},
     schedule::{get_schedule, Lecture, Request},
     utils::Period,
+    Error,
 };
 
 fn main() -> Result<(), Error> {
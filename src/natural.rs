@@ -0,0 +1,670 @@
+use crate::{
+    calendar::DateRange,
+    errors::{Error, ParseError, Result},
+    utils::Period,
+};
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, Weekday};
+use chrono_tz::Tz;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/** Parse a Ukrainian or English period description, relative to `today`, into a [`Period`] in `time_zone`.
+
+Understands single days ("завтра", "в п'ятницю", "наступного понеділка", "16.10", "25 грудня", "October 16"),
+weeks and months ("цього тижня", "на наступному тижні", "в листопаді", "next month"),
+counts ("наступні 3 дні", "next 2 weeks"), weekends ("вихідні")
+and ranges of any of them ("з 3 по 7 березня", "3-7 березня", "пн-пт", "from March 3 to March 7").
+
+Weekdays without "цей"/"наступний"/"минулий" mean their next occurrence, today included.
+Dates without a year are in the year of `today`. A range end that would come before its start moves to the next month
+if it is a bare day ("з 30 січня по 2") and to the next year if both ends name a month ("з 28 грудня по 3 січня").
+
+You probably want [`Period::from_natural`], which parses relative to the current day in Kyiv time.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::{errors::ParseError, natural::parse_period, utils::DEFAULT_TIME_ZONE};
+use chrono::NaiveDate;
+
+// Wednesday.
+let today = NaiveDate::from_ymd_opt(2024, 10, 16).unwrap();
+
+let corpus = [
+    ("сьогодні", "2024-10-16", "2024-10-16"),
+    ("Завтра", "2024-10-17", "2024-10-17"),
+    ("післязавтра", "2024-10-18", "2024-10-18"),
+    ("вчора", "2024-10-15", "2024-10-15"),
+    ("в понеділок", "2024-10-21", "2024-10-21"),
+    ("у середу", "2024-10-16", "2024-10-16"),
+    ("в п’ятницю?", "2024-10-18", "2024-10-18"),
+    ("наступної п'ятниці", "2024-10-25", "2024-10-25"),
+    ("минулого вівторка", "2024-10-08", "2024-10-08"),
+    ("цього тижня", "2024-10-14", "2024-10-20"),
+    ("на наступному тижні", "2024-10-21", "2024-10-27"),
+    ("минулий тиждень", "2024-10-07", "2024-10-13"),
+    ("вихідні", "2024-10-19", "2024-10-20"),
+    ("16.10", "2024-10-16", "2024-10-16"),
+    ("16.10.2024", "2024-10-16", "2024-10-16"),
+    ("2024-10-16", "2024-10-16", "2024-10-16"),
+    ("25 грудня", "2024-12-25", "2024-12-25"),
+    ("1-го вересня 2025 року", "2025-09-01", "2025-09-01"),
+    ("в листопаді", "2024-11-01", "2024-11-30"),
+    ("наступного місяця", "2024-11-01", "2024-11-30"),
+    ("березень 2025", "2025-03-01", "2025-03-31"),
+    ("наступні 3 дні", "2024-10-16", "2024-10-18"),
+    ("з 3 по 7 березня", "2024-03-03", "2024-03-07"),
+    ("3-7 березня", "2024-03-03", "2024-03-07"),
+    ("з 16.10 до 20.10", "2024-10-16", "2024-10-20"),
+    ("з 28 грудня по 3 січня", "2024-12-28", "2025-01-03"),
+    ("з 30 січня по 2", "2024-01-30", "2024-02-02"),
+    ("пн-пт", "2024-10-21", "2024-10-25"),
+    ("з понеділка по середу", "2024-10-21", "2024-10-23"),
+    ("today", "2024-10-16", "2024-10-16"),
+    ("on Friday", "2024-10-18", "2024-10-18"),
+    ("next monday", "2024-10-21", "2024-10-21"),
+    ("next week", "2024-10-21", "2024-10-27"),
+    ("October 16, 2024", "2024-10-16", "2024-10-16"),
+    ("16th of October", "2024-10-16", "2024-10-16"),
+    ("next 2 weeks", "2024-10-16", "2024-10-29"),
+    ("from March 3 to March 7", "2024-03-03", "2024-03-07"),
+];
+
+for (query, start, end) in corpus {
+    let period = parse_period(query, today, DEFAULT_TIME_ZONE)?;
+    assert_eq!(period.start_time.date_naive().to_string(), start, "{}", query);
+    assert_eq!(period.end_time.date_naive().to_string(), end, "{}", query);
+}
+
+let error = |query: &str| match parse_period(query, today, DEFAULT_TIME_ZONE) {
+    Err(Error::Parse(error)) => error,
+    other => panic!("{}: {:?}", query, other),
+};
+assert!(matches!(error(" "), ParseError::EmptyPeriodDescription));
+assert!(matches!(error("завтра вдень"), ParseError::UnknownPeriodWord(word) if word == "вдень"));
+assert!(matches!(error("31.02"), ParseError::NonexistentDate(date) if date == "31.02"));
+assert!(matches!(error("з понеділка"), ParseError::IncompletePeriodRange(range) if range == "з понеділка"));
+assert!(matches!(error("з 30 по 2 березня"), ParseError::InvertedPeriod(_)));
+assert!(matches!(error("з 5 по 3 березня"), ParseError::InvertedPeriod(_)));
+assert!(matches!(error("наступні 99999999 днів"), ParseError::CountOutOfRange(_)));
+assert!(matches!(error("next 2000000000000 days"), ParseError::CountOutOfRange(_)));
+assert!(matches!(error("next 9223372036854775807 weeks"), ParseError::CountOutOfRange(_)));
+assert!(matches!(error("next 99999999999999999999 days"), ParseError::CountOutOfRange(_)));
+assert!(matches!(error("наступні 100000 днів"), ParseError::YearOutOfRange(_)));
+# Ok::<(), Error>(())
+```
+
+# Errors
+This function fails if:
+ * [`ParseError::EmptyPeriodDescription`] - `input` has no words.
+ * [`ParseError::UnknownPeriodWord`] - `input` has a word that is not understood, or words in an unknown order.
+ * [`ParseError::IncompletePeriodRange`] - Range misses its start or its end.
+ * [`ParseError::NonexistentDate`] - Date like "31.02" doesn't exist.
+ * [`ParseError::CountOutOfRange`] - Count like "наступні 99999999 днів" goes beyond any representable date.
+ * [`Period::validate`] fails, e.g. for "з 2025-03-07 по 2025-03-03".
+**/
+pub fn parse_period(input: &str, today: NaiveDate, time_zone: Tz) -> Result<Period> {
+    let text = normalize(input);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() {
+        return Err(Error::from(ParseError::EmptyPeriodDescription));
+    }
+
+    let period = parse_range(&words, today)?.period_in(time_zone);
+    period.validate()?;
+    Ok(period)
+}
+
+/// Words skipped at the beginning of a description, e.g. "в понеділок", "on monday".
+const PREPOSITIONS: &[&str] = &["в", "у", "во", "на", "on", "in", "for", "at", "of"];
+
+const RANGE_STARTS: &[&str] = &["з", "із", "зі", "від", "from", "between"];
+
+const RANGE_ENDS: &[&str] = &["по", "до", "to", "till", "until", "and", "-"];
+
+/// Days relative to today.
+const DAYS: &[(&str, i64)] = &[
+    ("сьогодні", 0),
+    ("today", 0),
+    ("завтра", 1),
+    ("tomorrow", 1),
+    ("післязавтра", 2),
+    ("day after tomorrow", 2),
+    ("вчора", -1),
+    ("учора", -1),
+    ("yesterday", -1),
+    ("позавчора", -2),
+    ("day before yesterday", -2),
+];
+
+/// Beginnings of Ukrainian weekday names shared by all their cases.
+const WEEKDAY_STEMS: &[(&str, Weekday)] = &[
+    ("понеділ", Weekday::Mon),
+    ("вівтор", Weekday::Tue),
+    ("серед", Weekday::Wed),
+    ("четвер", Weekday::Thu),
+    ("п'ятниц", Weekday::Fri),
+    ("субот", Weekday::Sat),
+    ("неділ", Weekday::Sun),
+];
+
+const WEEKDAY_WORDS: &[(&str, Weekday)] = &[
+    ("пн", Weekday::Mon),
+    ("вт", Weekday::Tue),
+    ("ср", Weekday::Wed),
+    ("чт", Weekday::Thu),
+    ("пт", Weekday::Fri),
+    ("сб", Weekday::Sat),
+    ("нд", Weekday::Sun),
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+    ("mon", Weekday::Mon),
+    ("tue", Weekday::Tue),
+    ("wed", Weekday::Wed),
+    ("thu", Weekday::Thu),
+    ("fri", Weekday::Fri),
+    ("sat", Weekday::Sat),
+    ("sun", Weekday::Sun),
+];
+
+/// Beginnings of Ukrainian month names shared by all their cases.
+const MONTH_STEMS: &[(&str, u32)] = &[
+    ("січ", 1),
+    ("лют", 2),
+    ("берез", 3),
+    ("квіт", 4),
+    ("трав", 5),
+    ("черв", 6),
+    ("лип", 7),
+    ("серп", 8),
+    ("верес", 9),
+    ("жовт", 10),
+    ("листопад", 11),
+    ("груд", 12),
+];
+
+const MONTH_WORDS: &[(&str, u32)] = &[
+    ("january", 1),
+    ("february", 2),
+    ("march", 3),
+    ("april", 4),
+    ("may", 5),
+    ("june", 6),
+    ("july", 7),
+    ("august", 8),
+    ("september", 9),
+    ("october", 10),
+    ("november", 11),
+    ("december", 12),
+    ("jan", 1),
+    ("feb", 2),
+    ("mar", 3),
+    ("apr", 4),
+    ("jun", 6),
+    ("jul", 7),
+    ("aug", 8),
+    ("sep", 9),
+    ("sept", 9),
+    ("oct", 10),
+    ("nov", 11),
+    ("dec", 12),
+];
+
+const WEEKEND_WORDS: &[&str] = &["вихідні", "вихідних", "weekend", "weekends"];
+
+const YEAR_WORDS: &[&str] = &["рік", "року", "р"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+}
+
+fn parse_range(words: &[&str], today: NaiveDate) -> Result<DateRange> {
+    let incomplete = || Error::from(ParseError::IncompletePeriodRange(words.join(" ")));
+
+    let (left, right) = if RANGE_STARTS.contains(&words[0]) {
+        let rest = &words[1..];
+        match rest.iter().position(|word| RANGE_ENDS.contains(word)) {
+            Some(index) => (&rest[..index], &rest[index + 1..]),
+            None => return Err(incomplete()),
+        }
+    } else {
+        match words.iter().position(|word| *word == "-") {
+            Some(index) => (&words[..index], &words[index + 1..]),
+            None => return parse_single(words, today, today),
+        }
+    };
+
+    if left.is_empty() || right.is_empty() {
+        return Err(incomplete());
+    }
+
+    // "з 3 по 7 березня": a bare day takes its month and year from the other end.
+    // A bare-day start after the end is left inverted for `Period::validate` to report.
+    let (start, end) = match (bare_day(left), bare_day(right)) {
+        (Some(day), None) => {
+            let end = parse_single(right, today, today)?;
+            let start = make_date(end.start.year(), end.start.month(), day, left)?;
+            return Ok(DateRange::new(start, end.end));
+        }
+        (None, Some(day)) => {
+            let start = parse_single(left, today, today)?.start;
+            let end = make_date(start.year(), start.month(), day, right)?;
+            if end >= start {
+                return Ok(DateRange::new(start, end));
+            }
+
+            // "з 30 січня по 2": the end is in the next month.
+            let next = start
+                .with_day(1)
+                .and_then(|first| first.checked_add_months(Months::new(1)))
+                .ok_or_else(|| Error::from(ParseError::NonexistentDate(right.join(" "))))?;
+            return Ok(DateRange::new(
+                start,
+                make_date(next.year(), next.month(), day, right)?,
+            ));
+        }
+        _ => {
+            let start = parse_single(left, today, today)?.start;
+            (start, parse_single(right, today, start)?.end)
+        }
+    };
+
+    // "з 28 грудня по 3 січня": the end is in the next year.
+    if end < start
+        && names_month(left)
+        && names_month(right)
+        && !right.iter().any(|word| is_year(word))
+    {
+        if let Some(end) = end.checked_add_months(Months::new(12)) {
+            return Ok(DateRange::new(start, end));
+        }
+    }
+
+    Ok(DateRange::new(start, end))
+}
+
+/** Parse a description of a single day, week or month.
+
+Weekdays without a qualifier are looked up from `anchor`, the start of the range for its end and `today` otherwise.
+**/
+fn parse_single(words: &[&str], today: NaiveDate, anchor: NaiveDate) -> Result<DateRange> {
+    let mut words = words;
+    while words.len() > 1 && PREPOSITIONS.contains(&words[0]) {
+        words = &words[1..];
+    }
+    let text = words.join(" ");
+
+    if let Some((_, offset)) = DAYS.iter().find(|(phrase, _)| *phrase == text) {
+        return Ok(single(today + Duration::days(*offset)));
+    }
+
+    if let Some(range) = parse_relative(words, today, anchor) {
+        return Ok(range);
+    }
+
+    if let Some(range) = parse_count(&text, today)? {
+        return Ok(range);
+    }
+
+    if let Some(range) = parse_date(&text, today)? {
+        return Ok(range);
+    }
+
+    Err(Error::from(ParseError::UnknownPeriodWord(unknown_word(
+        words,
+    ))))
+}
+
+/** Weekdays, weeks, months and weekends, with an optional "цей"/"наступний"/"минулий" qualifier.
+**/
+fn parse_relative(words: &[&str], today: NaiveDate, anchor: NaiveDate) -> Option<DateRange> {
+    let (offset, words) = match words {
+        [first, rest @ ..] if !rest.is_empty() && qualifier(first).is_some() => {
+            (qualifier(first), rest)
+        }
+        _ => (None, words),
+    };
+    let [word] = words else {
+        return None;
+    };
+    let monday = week_start(today);
+
+    if let Some(unit) = unit(word) {
+        let offset = offset?;
+        return Some(match unit {
+            Unit::Day => single(today + Duration::days(offset)),
+            Unit::Week => {
+                let start = monday + Duration::weeks(offset);
+                DateRange::new(start, start + Duration::days(6))
+            }
+            Unit::Month => {
+                let current = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?;
+                let first = if offset < 0 {
+                    current.checked_sub_months(Months::new(offset.unsigned_abs() as u32))?
+                } else {
+                    current.checked_add_months(Months::new(offset as u32))?
+                };
+                whole_month(first.year(), first.month())?
+            }
+        });
+    }
+
+    if WEEKEND_WORDS.contains(word) {
+        let saturday = monday + Duration::weeks(offset.unwrap_or(0)) + Duration::days(5);
+        return Some(DateRange::new(saturday, saturday + Duration::days(1)));
+    }
+
+    if let Some(weekday) = weekday(word) {
+        let date = match offset {
+            Some(offset) => {
+                monday
+                    + Duration::weeks(offset)
+                    + Duration::days(weekday.num_days_from_monday() as i64)
+            }
+            None => {
+                let ahead = (weekday.num_days_from_monday() + 7
+                    - anchor.weekday().num_days_from_monday())
+                    % 7;
+                anchor + Duration::days(ahead as i64)
+            }
+        };
+        return Some(single(date));
+    }
+
+    if let Some(month) = month(word) {
+        return whole_month(today.year() + offset.unwrap_or(0) as i32, month);
+    }
+
+    None
+}
+
+/** "наступні 3 дні", "next 2 weeks": that many days or weeks starting today.
+
+Returns `Ok(None)` if `text` is not a count at all.
+**/
+fn parse_count(text: &str, today: NaiveDate) -> Result<Option<DateRange>> {
+    static COUNT: OnceLock<Regex> = OnceLock::new();
+    let regex = COUNT.get_or_init(|| {
+        Regex::new(r"^(?:\p{L}+\s+)?([0-9]+)\s+(\p{L}+)$").expect("count regex is valid")
+    });
+
+    let Some(captures) = regex.captures(text) else {
+        return Ok(None);
+    };
+    if let Some(first) = text.split_whitespace().next() {
+        if first.parse::<u32>().is_err()
+            && qualifier(first) != Some(1)
+            && !first.starts_with("найближч")
+        {
+            return Ok(None);
+        }
+    }
+
+    let multiplier: i64 = match unit(&captures[2]) {
+        Some(Unit::Day) => 1,
+        Some(Unit::Week) => 7,
+        Some(Unit::Month) | None => return Ok(None),
+    };
+    let out_of_range = || Error::from(ParseError::CountOutOfRange(String::from(text)));
+
+    let days = captures[1]
+        .parse::<i64>()
+        .ok()
+        .and_then(|count| count.checked_mul(multiplier))
+        .ok_or_else(out_of_range)?;
+    if days < 1 {
+        return Ok(None);
+    }
+
+    let end = today
+        .checked_add_days(Days::new(days as u64 - 1))
+        .ok_or_else(out_of_range)?;
+    Ok(Some(DateRange::new(today, end)))
+}
+
+/** Numeric and written dates, and months of a given year.
+
+Returns `Ok(None)` if `text` is not a date at all.
+**/
+fn parse_date(text: &str, today: NaiveDate) -> Result<Option<DateRange>> {
+    static ISO: OnceLock<Regex> = OnceLock::new();
+    static NUMERIC: OnceLock<Regex> = OnceLock::new();
+    static DAY_MONTH: OnceLock<Regex> = OnceLock::new();
+    static MONTH_DAY: OnceLock<Regex> = OnceLock::new();
+    static MONTH_YEAR: OnceLock<Regex> = OnceLock::new();
+
+    let iso = ISO.get_or_init(|| {
+        Regex::new(r"^(\d{4})-(\d{1,2})-(\d{1,2})$").expect("iso date regex is valid")
+    });
+    let numeric = NUMERIC.get_or_init(|| {
+        Regex::new(r"^(\d{1,2})[./](\d{1,2})(?:[./](\d{4}|\d{2}))?$")
+            .expect("numeric date regex is valid")
+    });
+    let day_month = DAY_MONTH.get_or_init(|| {
+        Regex::new(r"^(\d{1,2})\s+(?:of\s+)?([\p{L}']+)\.?(?:,?\s+(\d{4}))?(?:\s+(?:рік|року|р))?$")
+            .expect("day month regex is valid")
+    });
+    let month_day = MONTH_DAY.get_or_init(|| {
+        Regex::new(r"^([\p{L}']+)\.?\s+(\d{1,2})(?:,?\s+(\d{4}))?$")
+            .expect("month day regex is valid")
+    });
+    let month_year = MONTH_YEAR.get_or_init(|| {
+        Regex::new(r"^([\p{L}']+)\s+(\d{4})(?:\s+(?:рік|року|р))?$")
+            .expect("month year regex is valid")
+    });
+
+    let number = |value: &str| value.parse::<u32>().unwrap_or(0);
+    let year = |value: Option<regex::Match>| match value {
+        Some(value) if value.as_str().len() == 2 => 2000 + number(value.as_str()) as i32,
+        Some(value) => number(value.as_str()) as i32,
+        None => today.year(),
+    };
+
+    let (year, month, day) = if let Some(captures) = iso.captures(text) {
+        (
+            number(&captures[1]) as i32,
+            number(&captures[2]),
+            number(&captures[3]),
+        )
+    } else if let Some(captures) = numeric.captures(text) {
+        (
+            year(captures.get(3)),
+            number(&captures[2]),
+            number(&captures[1]),
+        )
+    } else if let Some(captures) = day_month.captures(text) {
+        let Some(month) = month(&captures[2]) else {
+            return Ok(None);
+        };
+        (year(captures.get(3)), month, number(&captures[1]))
+    } else if let Some(captures) = month_day.captures(text) {
+        let Some(month) = month(&captures[1]) else {
+            return Ok(None);
+        };
+        (year(captures.get(3)), month, number(&captures[2]))
+    } else if let Some(captures) = month_year.captures(text) {
+        let Some(month) = month(&captures[1]) else {
+            return Ok(None);
+        };
+        return Ok(whole_month(year(captures.get(2)), month));
+    } else if let Some(day) = bare_day(&[text]) {
+        (today.year(), today.month(), day)
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(single(make_date(year, month, day, &[text])?)))
+}
+
+/** Lowercase `input`, unify apostrophes and dashes, drop ordinal suffixes and trailing punctuation.
+**/
+fn normalize(input: &str) -> String {
+    static ORDINAL: OnceLock<Regex> = OnceLock::new();
+    let ordinal = ORDINAL.get_or_init(|| {
+        Regex::new(r"(\d)-?(?:го|ого|е|st|nd|rd|th)\b").expect("ordinal regex is valid")
+    });
+
+    let text = input
+        .to_lowercase()
+        .replace(['’', 'ʼ', '`', '′'], "'")
+        .replace(['–', '—'], "-");
+    let text = ordinal.replace_all(&text, "$1");
+
+    text.split_whitespace()
+        .map(|word| {
+            // Keep ISO dates whole, any other dash separates the ends of a range.
+            let parts: Vec<&str> = word.split('-').collect();
+            if parts.len() == 3
+                && parts[0].len() == 4
+                && parts
+                    .iter()
+                    .all(|part| part.chars().all(|char| char.is_ascii_digit()))
+            {
+                String::from(word)
+            } else {
+                word.replace('-', " - ")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+        .trim_end_matches(['?', '!', '.', ',', ' '])
+        .to_string()
+}
+
+/// First word of `words` that is not part of any known description, all of them if every word is known.
+fn unknown_word(words: &[&str]) -> String {
+    let known = |word: &str| {
+        let word = word.trim_matches(['.', ',']);
+        word.chars()
+            .all(|char| char.is_ascii_digit() || ".-/".contains(char))
+            || PREPOSITIONS.contains(&word)
+            || RANGE_STARTS.contains(&word)
+            || RANGE_ENDS.contains(&word)
+            || YEAR_WORDS.contains(&word)
+            || WEEKEND_WORDS.contains(&word)
+            || DAYS
+                .iter()
+                .any(|(phrase, _)| phrase.split(' ').any(|part| part == word))
+            || qualifier(word).is_some()
+            || word.starts_with("найближч")
+            || unit(word).is_some()
+            || weekday(word).is_some()
+            || month(word).is_some()
+    };
+
+    match words.iter().find(|word| !known(word)) {
+        Some(word) => String::from(*word),
+        None => words.join(" "),
+    }
+}
+
+/// Week offset of "наступний", "цей", "минулий" and their forms.
+fn qualifier(word: &str) -> Option<i64> {
+    if word.starts_with("наступн") || ["next", "following"].contains(&word) {
+        Some(1)
+    } else if word.starts_with("минул")
+        || word.starts_with("попередн")
+        || ["last", "previous", "past"].contains(&word)
+    {
+        Some(-1)
+    } else if word.starts_with("поточн")
+        || [
+            "цей",
+            "ця",
+            "це",
+            "цього",
+            "цієї",
+            "цю",
+            "цьому",
+            "цій",
+            "this",
+            "current",
+        ]
+        .contains(&word)
+    {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+fn unit(word: &str) -> Option<Unit> {
+    if word.starts_with("дн") || ["день", "day", "days"].contains(&word) {
+        Some(Unit::Day)
+    } else if word.starts_with("тиж") || ["week", "weeks"].contains(&word) {
+        Some(Unit::Week)
+    } else if word.starts_with("місяц") || ["month", "months"].contains(&word) {
+        Some(Unit::Month)
+    } else {
+        None
+    }
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    WEEKDAY_WORDS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .or_else(|| {
+            WEEKDAY_STEMS
+                .iter()
+                .find(|(stem, _)| word.starts_with(stem))
+        })
+        .map(|(_, weekday)| *weekday)
+}
+
+fn month(word: &str) -> Option<u32> {
+    let word = word.trim_end_matches('.');
+    MONTH_WORDS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .or_else(|| MONTH_STEMS.iter().find(|(stem, _)| word.starts_with(stem)))
+        .map(|(_, month)| *month)
+}
+
+fn bare_day(words: &[&str]) -> Option<u32> {
+    match words {
+        [word] if word.len() <= 2 => word.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Say if `words` name a month explicitly, by its name or as a numeric date.
+fn names_month(words: &[&str]) -> bool {
+    words
+        .iter()
+        .any(|word| month(word).is_some() || word.contains(['.', '/']) || is_year(word))
+}
+
+fn is_year(word: &str) -> bool {
+    word.split(['.', '/', '-'])
+        .any(|part| part.len() == 4 && part.chars().all(|char| char.is_ascii_digit()))
+}
+
+fn make_date(year: i32, month: u32, day: u32, words: &[&str]) -> Result<NaiveDate> {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => Ok(date),
+        None => Err(Error::from(ParseError::NonexistentDate(words.join(" ")))),
+    }
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+fn whole_month(year: i32, month: u32) -> Option<DateRange> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let last = first.checked_add_months(Months::new(1))? - Duration::days(1);
+    Some(DateRange::new(first, last))
+}
+
+fn single(date: NaiveDate) -> DateRange {
+    DateRange::new(date, date)
+}
@@ -0,0 +1,248 @@
+use crate::{
+    errors::{Error, Result},
+    transport::TransportResponse,
+};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Status codes retried by default.
+pub(crate) const RETRYABLE_STATUSES: &[u16] = &[408, 425, 429, 500, 502, 503, 504];
+
+/** Jitter applied to the backoff delay.
+# Variants
+ * `None` - wait exactly the computed backoff.
+ * `Full` - wait a random delay between zero and the computed backoff.
+ * `Equal` - wait half of the computed backoff plus a random delay up to the other half.
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    None,
+    Full,
+    Equal,
+}
+
+/** Retry policy applied to every request made by a client.
+
+Failed requests are retried with exponential backoff: the n-th retry waits `base_delay * 2^(n - 1)`,
+capped at `max_delay` and randomized by [`Jitter`].
+If the server sends a `Retry-After` header and the policy honours it, the client waits at least that long (still capped at `max_delay`).
+
+# Examples
+```
+# use nure_tools::{
+#     groups::Group,
+#     retry::RetryPolicy,
+#     transport::{FixtureTransport, TransportResponse},
+#     Client, Error,
+# };
+use std::time::Duration;
+
+let transport = FixtureTransport::new()
+    .with_response("/lists/groups", TransportResponse::new(503, ""))
+    .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#);
+
+let client = Client::builder()
+    .transport(transport.clone())
+    .retry_policy(
+        RetryPolicy::new()
+            .max_attempts(5)
+            .base_delay(Duration::from_millis(1)),
+    )
+    .build()?;
+
+let groups: Vec<Group> = client.get_groups()?;
+assert_eq!(groups.len(), 1);
+assert_eq!(transport.requests().len(), 2);
+
+transport.push_response(
+    "/lists/teachers",
+    TransportResponse::new(429, "").with_header("Retry-After", "0"),
+);
+let error: Error = client.get_teachers().unwrap_err();
+assert!(error.is_retryable());
+if let Error::AttemptsExhausted { attempts, last } = error {
+    assert_eq!(attempts, 5);
+    assert!(matches!(*last, Error::RateLimited { retry_after: Some(_) }));
+} else {
+    panic!("expected Error::AttemptsExhausted");
+}
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: Jitter,
+    retryable_statuses: Vec<u16>,
+    retry_transport_errors: bool,
+    honour_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /** Create a new RetryPolicy with default settings.
+
+    Defaults are: 3 attempts, 200ms base delay, 5s max delay, [`Jitter::Full`],
+    retry on `408`, `425`, `429`, `500`, `502`, `503`, `504` and on transport errors, honour `Retry-After`.
+    **/
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: Jitter::Full,
+            retryable_statuses: RETRYABLE_STATUSES.to_vec(),
+            retry_transport_errors: true,
+            honour_retry_after: true,
+        }
+    }
+
+    /** Create a new RetryPolicy that never retries.
+     **/
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /** Set the maximum number of attempts, including the first one.
+
+    Values below 1 are treated as 1.
+    **/
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /** Set the delay before the first retry.
+     **/
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /** Set the upper bound of a single delay.
+     **/
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /** Set the [`Jitter`] applied to every delay.
+     **/
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /** Set the status codes that are worth retrying.
+     **/
+    pub fn retryable_statuses(mut self, retryable_statuses: &[u16]) -> Self {
+        self.retryable_statuses = retryable_statuses.to_vec();
+        self
+    }
+
+    /** Set whether transport errors and timeouts (no response at all) are worth retrying.
+     **/
+    pub fn retry_transport_errors(mut self, retry_transport_errors: bool) -> Self {
+        self.retry_transport_errors = retry_transport_errors;
+        self
+    }
+
+    /** Set whether the `Retry-After` header is honoured.
+     **/
+    pub fn honour_retry_after(mut self, honour_retry_after: bool) -> Self {
+        self.honour_retry_after = honour_retry_after;
+        self
+    }
+
+    /** Maximum number of attempts, including the first one.
+     **/
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /** Say if the outcome of the `attempt`-th attempt should be retried.
+
+    Errors returned by the transport are only retried if they are [`Error::Transport`] or [`Error::Timeout`].
+
+    # Examples
+    ```
+    # use nure_tools::{errors::ParseError, retry::RetryPolicy, Error};
+    let policy = RetryPolicy::new();
+
+    assert!(policy.should_retry(1, &Err(Error::Timeout("no response".into()))));
+    assert!(!policy.should_retry(1, &Err(Error::from(ParseError::EmptyPeriodDescription))));
+    assert!(!policy.should_retry(3, &Err(Error::Timeout("no response".into()))));
+    ```
+    **/
+    pub fn should_retry(&self, attempt: u32, outcome: &Result<TransportResponse>) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        match outcome {
+            Ok(response) => self.retryable_statuses.contains(&response.status),
+            Err(Error::Transport(_) | Error::Timeout(_)) => self.retry_transport_errors,
+            Err(_) => false,
+        }
+    }
+
+    /** Delay before the next attempt after the `attempt`-th one failed with `outcome`.
+     **/
+    pub fn delay(&self, attempt: u32, outcome: &Result<TransportResponse>) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.pow(exponent))
+            .min(self.max_delay);
+
+        let delay = match self.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => backoff.mul_f64(random_fraction()),
+            Jitter::Equal => backoff / 2 + (backoff / 2).mul_f64(random_fraction()),
+        };
+
+        let retry_after = match outcome {
+            Ok(response) if self.honour_retry_after => response.retry_after(),
+            _ => None,
+        };
+
+        match retry_after {
+            Some(retry_after) => delay.max(retry_after).min(self.max_delay),
+            None => delay,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/** Helper function to attach the attempt count to the error of the last attempt.
+**/
+pub(crate) fn exhausted(error: Error, attempts: u32) -> Error {
+    if attempts > 1 {
+        Error::AttemptsExhausted {
+            attempts,
+            last: Box::new(error),
+        }
+    } else {
+        error
+    }
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
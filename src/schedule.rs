@@ -1,17 +1,25 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
 use crate::{
-    errors::RequestError,
-    groups::{parse_group_json, Group},
-    lecture_rooms::LectureRoom,
-    teachers::{parse_teacher_json, Teacher},
-    utils::{get_wrapper, Period},
+    batch::Chunking,
+    cache::Fetched,
+    errors::{Error, ParseError, RecordError, RequestError, Result},
+    groups::Group,
+    lecture_rooms::{LectureRoom, RoomLocation},
+    teachers::Teacher,
+    utils::Period,
+    wire::{decode, decode_lenient, decode_record, Decoded, LectureRecord, SubjectRecord},
 };
-use anyhow::{anyhow, Result};
-use reqwest::blocking::get;
+#[cfg(feature = "blocking")]
+use crate::{client::default_client, Client};
+use chrono::{DateTime, NaiveDate, Weekday};
+use chrono_tz::Tz;
 use serde_json::{self, Map, Value};
+use std::collections::{BTreeMap, HashMap};
 
 /** Get schedule function.
 
-Returns shedule for the given request in `Vec<Lecture>` format.
+Returns shedule for the given request in `Vec<Lecture>` format, convert it into a [`Schedule`] to query it.
 
 # Arguments
  * request - accepts a [`Request`] enum with a [`Group`]/[`Teacher`]/[`LectureRoom`] object inside.
@@ -19,7 +27,7 @@ Returns shedule for the given request in `Vec<Lecture>` format.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::{
 #     groups::{find_group, Group},
 #     schedule::{get_schedule, Lecture, Request},
@@ -40,13 +48,139 @@ for group in groups_response {
 
 # Errors
 This function fails if:
- * `RequestError::GetFailed` - Get request fails.
- * `RequestError::NotJson` - Server returns value not in json format.
+ * `Error::Transport` - Get request fails.
+ * `Error::Decode` - Server returns value not in json format.
  * `RequestError::BadResponse` - Server returns any response except 200.
  * `RequestError::InvalidReturn` - Server returns value in unexpected format.
+ * `ParseError::InvalidRecord` - Any of the returned lectures is invalid.
+ * `ParseError::InvertedPeriod`, `ParseError::YearOutOfRange` - `period` is invalid, see [`Period::validate`].
+ * `ParseError::PeriodTooLong` - `period` is longer than [`ClientBuilder::max_period_span`].
 
+[`ClientBuilder::max_period_span`]: `crate::ClientBuilder::max_period_span`
 **/
+#[cfg(feature = "blocking")]
 pub fn get_schedule(request: Request, period: Period) -> Result<Vec<Lecture>> {
+    default_client()?.get_schedule(request, period)
+}
+
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Get schedule function.
+
+    Same as [`get_schedule`], but uses this client.
+    **/
+    pub fn get_schedule(&self, request: Request, period: Period) -> Result<Vec<Lecture>> {
+        Ok(self.fetch_schedule(request, period)?.data)
+    }
+
+    /** Get schedule and say where it came from.
+
+    Same as [`Client::get_schedule`], but also returns the [`ResponseSource`].
+    Long periods are split into chunks if the client has a [`Chunking`], see [`Client::fetch_schedule_chunked`].
+
+    [`ResponseSource`]: `crate::cache::ResponseSource`
+    **/
+    pub fn fetch_schedule(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<Fetched<Vec<Lecture>>> {
+        if self.chunking == Chunking::Whole {
+            self.fetch_schedule_once(request, period)
+        } else {
+            self.fetch_schedule_chunked(request, period)?.into_fetched()
+        }
+    }
+
+    /** Helper function to get schedule with a single request.
+     **/
+    pub(crate) fn fetch_schedule_once(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<Fetched<Vec<Lecture>>> {
+        check_period(&period, self.max_period_span)?;
+        let (path, query) = schedule_path_and_query(&request, &period);
+        let fetched = self.fetch_json(&path, &query)?;
+        let mut decoded = schedule_from_response(fetched.data, self.lenient, self.time_zone)?;
+        if self.resolve_lecture_rooms {
+            if let Ok(lecture_rooms) = self.lecture_rooms_directory() {
+                resolve_lecture_rooms(&mut decoded.items, &lecture_rooms.data);
+            }
+        }
+        Ok(Fetched::new(decoded.items, fetched.source).with_warnings(decoded.warnings))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Get schedule function.
+
+    Async counterpart of [`Client::get_schedule`].
+    **/
+    pub async fn get_schedule(&self, request: Request, period: Period) -> Result<Vec<Lecture>> {
+        Ok(self.fetch_schedule(request, period).await?.data)
+    }
+
+    /** Get schedule and say where it came from.
+
+    Async counterpart of [`Client::fetch_schedule`].
+    **/
+    pub async fn fetch_schedule(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<Fetched<Vec<Lecture>>> {
+        if self.chunking == Chunking::Whole {
+            self.fetch_schedule_once(request, period).await
+        } else {
+            self.fetch_schedule_chunked(request, period)
+                .await?
+                .into_fetched()
+        }
+    }
+
+    /** Helper function to get schedule with a single request.
+     **/
+    pub(crate) async fn fetch_schedule_once(
+        &self,
+        request: Request,
+        period: Period,
+    ) -> Result<Fetched<Vec<Lecture>>> {
+        check_period(&period, self.max_period_span)?;
+        let (path, query) = schedule_path_and_query(&request, &period);
+        let fetched = self.fetch_json(&path, &query).await?;
+        let mut decoded = schedule_from_response(fetched.data, self.lenient, self.time_zone)?;
+        if self.resolve_lecture_rooms {
+            if let Ok(lecture_rooms) = self.lecture_rooms_directory().await {
+                resolve_lecture_rooms(&mut decoded.items, &lecture_rooms.data);
+            }
+        }
+        Ok(Fetched::new(decoded.items, fetched.source).with_warnings(decoded.warnings))
+    }
+}
+
+/** Helper function to check `period` before it is sent to the API.
+
+# Errors
+This function fails if:
+ * [`Period::validate`] fails.
+ * [`ParseError::PeriodTooLong`] - Period is longer than `max_span`.
+**/
+pub(crate) fn check_period(period: &Period, max_span: Option<std::time::Duration>) -> Result<()> {
+    // Spans too long for chrono are no limit at all.
+    match max_span.and_then(|max_span| chrono::Duration::from_std(max_span).ok()) {
+        Some(max_span) => period.validate_span(max_span),
+        None => period.validate(),
+    }
+}
+
+/** Helper function to build the schedule endpoint path and query for the given request.
+**/
+pub(crate) fn schedule_path_and_query(
+    request: &Request,
+    period: &Period,
+) -> (String, Vec<(&'static str, String)>) {
     let start_time = period.start_time.timestamp().to_string();
     let end_time = period.end_time.timestamp().to_string();
 
@@ -56,88 +190,118 @@ pub fn get_schedule(request: Request, period: Period) -> Result<Vec<Lecture>> {
         Request::LectureRoom(lecture_room) => ("auditories", lecture_room.id),
     };
 
-    let response = get_wrapper(get(format!(
-        "https://api.mindenit.tech/schedule/{}/{}?start={}&end={}",
-        request_type, request_id, start_time, end_time,
-    )))?;
+    (
+        format!("/schedule/{}/{}", request_type, request_id),
+        vec![("start", start_time), ("end", end_time)],
+    )
+}
 
-    let mut result: Vec<Lecture> = Vec::new();
+/** Helper function to turn the schedule endpoint response into `Vec<Lecture>` with periods in `time_zone`.
 
+In lenient mode invalid records are skipped and returned as warnings.
+**/
+pub(crate) fn schedule_from_response(
+    response: Value,
+    lenient: bool,
+    time_zone: Tz,
+) -> Result<Decoded<Lecture>> {
     if let Value::Array(vector) = response {
-        let mut lecture_room: String = String::new();
-        let mut start_time: i64 = 0;
-        let mut end_time: i64 = 0;
-        let mut number_pair: u8 = 0;
-        let mut lecture_type: String = String::new();
-        let mut teachers: Vec<Teacher> = vec![];
-        let mut groups: Vec<Group> = vec![];
-        let mut subject: Subject = Subject::default();
-
-        for element in vector {
-            if let Value::Object(mut obj) = element {
-                if let Value::String(st) = obj.get("auditory").unwrap() {
-                    lecture_room = st.clone();
-                }
-                if let Value::Number(n) = obj.get("startTime").unwrap() {
-                    start_time = n.as_i64().unwrap();
-                }
-                if let Value::Number(n) = obj.get("endTime").unwrap() {
-                    end_time = n.as_i64().unwrap();
-                }
-                if let Value::Number(n) = obj.get("numberPair").unwrap() {
-                    number_pair = n.as_i64().unwrap() as u8;
-                }
-                if let Value::String(st) = obj.get("type").unwrap() {
-                    lecture_type = st.clone();
-                }
-                if let Value::Array(vector) = obj.remove("teachers").unwrap() {
-                    teachers = parse_teacher_json(vector);
-                }
-                if let Value::Array(vector) = obj.remove("groups").unwrap() {
-                    groups = parse_group_json(vector);
-                }
-                if let Value::Object(obj) = obj.remove("subject").unwrap() {
-                    subject = parse_subject_json(obj);
-                }
-
-                result.push(Lecture::new(
-                    lecture_room.clone(),
-                    Period::from_timestamp(start_time, end_time)?,
-                    number_pair,
-                    lecture_type.clone(),
-                    teachers.clone(),
-                    groups.clone(),
-                    subject.clone(),
-                ));
-            };
+        let mut decoded = decode::<LectureRecord>(vector, lenient)?;
+        for lecture in &mut decoded.items {
+            lecture.period = lecture.period.with_time_zone(time_zone);
         }
-
-        Ok(result)
+        Ok(decoded)
     } else {
-        Err(anyhow!(RequestError::InvalidReturn))
+        Err(Error::from(RequestError::InvalidReturn))
     }
 }
 
-/** Helper function to parse subject json returned by API into [`Subject`] struct.
+/** Helper function to fill [`Lecture::room`] of every lecture from the lecture rooms list.
 
-You probably will never use it, but you can if you want, see example in [`get_schedule`] function source
+Names are matched case-insensitively, lectures with unknown rooms are left untouched.
 **/
-pub fn parse_subject_json(obj: Map<String, Value>) -> Subject {
-    let mut brief: String = String::new();
-    let mut id: i32 = 0;
-    let mut title: String = String::new();
+pub(crate) fn resolve_lecture_rooms(lectures: &mut [Lecture], lecture_rooms: &[LectureRoom]) {
+    let by_name: HashMap<String, &LectureRoom> = lecture_rooms
+        .iter()
+        .map(|lecture_room| (lecture_room.name.to_lowercase(), lecture_room))
+        .collect();
 
-    if let Value::String(st) = obj.get("brief").unwrap() {
-        brief = st.clone();
-    }
-    if let Value::Number(n) = obj.get("id").unwrap() {
-        id = n.as_i64().unwrap_or(0) as i32;
+    for lecture in lectures {
+        if let Some(lecture_room) = by_name.get(&lecture.lecture_room.to_lowercase()) {
+            lecture.room = Some((*lecture_room).clone());
+        }
     }
-    if let Value::String(st) = obj.get("title").unwrap() {
-        title = st.clone();
+}
+
+/** Helper function to parse lecture json returned by API into [`Lecture`] struct.
+
+You probably will never use it, but you can if you want, see example in [`get_schedule`] function source
+
+# Errors
+This function fails if:
+ * `ParseError::InvalidRecord` - Any of the records is not a valid lecture.
+**/
+pub fn parse_lecture_json(vector: Vec<Value>) -> Result<Vec<Lecture>> {
+    Ok(decode::<LectureRecord>(vector, false)?.items)
+}
+
+/** Helper function to parse lecture json returned by API into [`Lecture`] struct, skipping invalid records.
+
+Same as [`parse_lecture_json`], but returns the skipped records as warnings instead of failing.
+
+# Examples
+```
+# use nure_tools::schedule::{parse_lecture_json_lenient, Lecture};
+let json = serde_json::json!([
+    {
+        "id": 1,
+        "startTime": 1704175200,
+        "endTime": 1704180900,
+        "auditory": "287",
+        "numberPair": 2,
+        "type": "Лк",
+        "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+        "teachers": [{"id": 1, "fullName": "Терещенко Гліб Юрійович", "shortName": "Терещенко Г. Ю."}],
+        "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+    },
+    {
+        "id": 2,
+        "startTime": 1704181800,
+        "endTime": 1704187500,
+        "auditory": "287",
+        "numberPair": 3,
+        "type": "Лк",
+        "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+        "teachers": [{"id": 1, "fullName": "Терещенко Гліб Юрійович", "shortName": null}],
+        "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
     }
+]);
 
-    Subject::new(brief, id, title)
+let (lectures, warnings) = parse_lecture_json_lenient(json.as_array().unwrap().clone());
+assert_eq!(lectures.len(), 1);
+assert_eq!(warnings[0].record, 1);
+assert_eq!(warnings[0].field, "teachers[0].shortName");
+assert_eq!(warnings[0].found, "null");
+```
+**/
+pub fn parse_lecture_json_lenient(vector: Vec<Value>) -> (Vec<Lecture>, Vec<RecordError>) {
+    let decoded = decode_lenient::<LectureRecord>(vector);
+    (decoded.items, decoded.warnings)
+}
+
+/** Helper function to parse subject json returned by API into [`Subject`] struct.
+
+You probably will never use it, but you can if you want, see example in [`get_schedule`] function source
+
+# Errors
+This function fails if:
+ * `ParseError::InvalidRecord` - Json is not a valid subject.
+**/
+pub fn parse_subject_json(obj: Map<String, Value>) -> Result<Subject> {
+    match decode_record::<SubjectRecord>(0, &Value::Object(obj)) {
+        Ok(subject) => Ok(subject),
+        Err(error) => Err(Error::from(ParseError::InvalidRecord(error))),
+    }
 }
 
 /** Request enum to simplify the [`get_schedule`] function.
@@ -146,20 +310,89 @@ pub fn parse_subject_json(obj: Map<String, Value>) -> Subject {
  * `Teacher` - require a [`Teacher`] to parse id from it.
  * `LectureRoom` - require a [`LectureRoom`] to parse id from it.
 **/
+#[derive(Debug, Clone)]
 pub enum Request {
     Group(Group),
     Teacher(Teacher),
     LectureRoom(LectureRoom),
 }
 
+impl Request {
+    /** [`ScheduleKey`] of the entity this request is for.
+     **/
+    pub fn key(&self) -> ScheduleKey {
+        match self {
+            Self::Group(group) => ScheduleKey::Group(group.id),
+            Self::Teacher(teacher) => ScheduleKey::Teacher(teacher.id),
+            Self::LectureRoom(lecture_room) => ScheduleKey::LectureRoom(lecture_room.id),
+        }
+    }
+}
+
+/** Kind and id of the entity a schedule belongs to.
+# Variants
+ * `Group` - id of a [`Group`].
+ * `Teacher` - id of a [`Teacher`].
+ * `LectureRoom` - id of a [`LectureRoom`].
+**/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScheduleKey {
+    Group(i32),
+    Teacher(i32),
+    LectureRoom(i32),
+}
+
 /** Massive Lacture struct.
+
+`lecture_room` is the raw room name returned by the API, `room` is the matching [`LectureRoom`]
+if the client resolves lecture rooms (see [`ClientBuilder::resolve_lecture_rooms`]) and the name is known.
+
+[`ClientBuilder::resolve_lecture_rooms`]: `crate::ClientBuilder::resolve_lecture_rooms`
+
+# Serialization
+With the `serde` feature Lecture and every type it contains are (de)serialized with their Rust field names,
+[`LectureType`] as its API abbreviation, [`Period`] is described in its own documentation.
+```
+# use nure_tools::Error;
+# use nure_tools::schedule::{Lecture, LectureType};
+# #[cfg(feature = "serde")]
+# {
+let json: &str = r#"{
+    "lecture_room": "287",
+    "period": {
+        "start_time": "2024-01-02T08:00:00+02:00",
+        "end_time": "2024-01-02T09:35:00+02:00",
+        "time_zone": "Europe/Kiev"
+    },
+    "number_pair": 1,
+    "lecture_type": "Лк",
+    "teachers": [{"id": 1, "full_name": "Терещенко Гліб Юрійович", "short_name": "Терещенко Г. Ю."}],
+    "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+    "subject": {"brief": "ООП", "id": 2, "title": "Об'єктно-орієнтоване програмування"}
+}"#;
+
+let lecture: Lecture = serde_json::from_str(json)?;
+let restored: Lecture = serde_json::from_value(serde_json::to_value(&lecture)?)?;
+
+assert_eq!(restored.groups[0].name, "ПЗПІ-23-2");
+assert_eq!(restored.lecture_type, LectureType::Lecture);
+assert_eq!(restored.teachers[0].short_name, "Терещенко Г. Ю.");
+assert_eq!(restored.period.start_time, lecture.period.start_time);
+assert_eq!(serde_json::to_value(&restored)?, serde_json::to_value(&lecture)?);
+# }
+# Ok::<(), Error>(())
+```
 **/
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lecture {
     pub lecture_room: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub room: Option<LectureRoom>,
     pub period: Period,
     pub number_pair: u8,
-    pub lecture_type: String,
+    pub lecture_type: LectureType,
     pub teachers: Vec<Teacher>,
     pub groups: Vec<Group>,
     pub subject: Subject,
@@ -167,6 +400,7 @@ pub struct Lecture {
 /** Subject struct.
 **/
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Subject {
     pub brief: String,
     pub id: i32,
@@ -174,17 +408,18 @@ pub struct Subject {
 }
 
 impl Lecture {
-    fn new(
+    pub(crate) fn new(
         lecture_room: String,
         period: Period,
         number_pair: u8,
-        lecture_type: String,
+        lecture_type: LectureType,
         teachers: Vec<Teacher>,
         groups: Vec<Group>,
         subject: Subject,
     ) -> Self {
         Self {
             lecture_room,
+            room: None,
             period,
             number_pair,
             lecture_type,
@@ -195,8 +430,488 @@ impl Lecture {
     }
 }
 
+impl Lecture {
+    /** [`RoomLocation`] parsed from the raw room name of this lecture.
+     **/
+    pub fn room_location(&self) -> RoomLocation {
+        RoomLocation::from(self.lecture_room.as_str())
+    }
+
+    /** [`Request`] for the schedule of the room of this lecture, if it was resolved.
+     **/
+    pub fn lecture_room_request(&self) -> Option<Request> {
+        self.room.clone().map(Request::LectureRoom)
+    }
+
+    /** Same Lecture with its period in `time_zone`.
+     **/
+    pub fn with_time_zone(&self, time_zone: Tz) -> Lecture {
+        Self {
+            period: self.period.with_time_zone(time_zone),
+            ..self.clone()
+        }
+    }
+
+    /** Period of this lecture in `time_zone`.
+     **/
+    pub fn period_in(&self, time_zone: Tz) -> Period {
+        self.period.with_time_zone(time_zone)
+    }
+}
+
 impl Subject {
-    fn new(brief: String, id: i32, title: String) -> Self {
+    pub(crate) fn new(brief: String, id: i32, title: String) -> Self {
         Self { brief, id, title }
     }
 }
+
+/** Lectures kept in chronological order, with queries over them.
+
+Lectures starting at the same time are ordered by their end. Days and weeks are counted in the time zone
+of every lecture, weeks start on Monday.
+
+# Examples
+```
+# use nure_tools::{
+#     schedule::{parse_lecture_json, LectureType, Schedule},
+#     Error,
+# };
+use chrono::{NaiveDate, TimeZone};
+use chrono_tz::Tz::Europe__Kiev;
+
+let lecture = |start: i64, pair: u8, kind: &str, subject: i32, room: &str| {
+    serde_json::json!({
+        "startTime": start,
+        "endTime": start + 5700,
+        "auditory": room,
+        "numberPair": pair,
+        "type": kind,
+        "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+        "teachers": [{"id": 5, "shortName": "Терещенко Г. Ю.", "fullName": "Терещенко Гліб Юрійович"}],
+        "subject": {"id": subject, "title": "Предмет", "brief": "П"}
+    })
+};
+let schedule: Schedule = parse_lecture_json(vec![
+    lecture(1704785400, 2, "Лб", 2, "287"),
+    lecture(1704094200, 2, "Лк", 2, "287"),
+    lecture(1704087900, 1, "Пз", 3, "165"),
+])?
+.into();
+
+let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+assert_eq!(schedule.len(), 3);
+assert_eq!(schedule.on_day(monday).len(), 2);
+assert_eq!(schedule.first_of_day(monday).map(|lecture| lecture.number_pair), Some(1));
+assert_eq!(schedule.by_week().len(), 2);
+
+assert_eq!(schedule.of_type(&LectureType::Laboratory).len(), 1);
+assert_eq!(schedule.of_subject(2).in_room("287").len(), 2);
+assert_eq!(schedule.with_teacher(5).with_group(10887035).len(), 3);
+
+let now = Europe__Kiev.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+assert_eq!(schedule.current_lecture(&now).map(|lecture| lecture.subject.id), Some(2));
+assert_eq!(schedule.next_lecture(&now).map(|lecture| lecture.lecture_type.is_lab()), Some(true));
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<Lecture>", into = "Vec<Lecture>")
+)]
+pub struct Schedule {
+    lectures: Vec<Lecture>,
+}
+
+impl Schedule {
+    /** Create a new Schedule from lectures in any order.
+     **/
+    pub fn new(lectures: Vec<Lecture>) -> Self {
+        let mut schedule = Self { lectures };
+        schedule.sort();
+        schedule
+    }
+
+    /** Add `lecture` to the schedule, keeping the order.
+     **/
+    pub fn insert(&mut self, lecture: Lecture) {
+        let index = self
+            .lectures
+            .partition_point(|existing| order(existing) <= order(&lecture));
+        self.lectures.insert(index, lecture);
+    }
+
+    /** Lectures of the schedule in chronological order.
+     **/
+    pub fn lectures(&self) -> &[Lecture] {
+        &self.lectures
+    }
+
+    /** Iterator over the lectures of the schedule in chronological order.
+     **/
+    pub fn iter(&self) -> std::slice::Iter<'_, Lecture> {
+        self.lectures.iter()
+    }
+
+    /** Number of lectures in the schedule.
+     **/
+    pub fn len(&self) -> usize {
+        self.lectures.len()
+    }
+
+    /** Say if the schedule has no lectures.
+     **/
+    pub fn is_empty(&self) -> bool {
+        self.lectures.is_empty()
+    }
+
+    /** Lectures starting on `date`.
+     **/
+    pub fn on_day(&self, date: NaiveDate) -> Vec<&Lecture> {
+        self.lectures
+            .iter()
+            .filter(|lecture| day_of(lecture) == date)
+            .collect()
+    }
+
+    /** First lecture starting on `date`.
+     **/
+    pub fn first_of_day(&self, date: NaiveDate) -> Option<&Lecture> {
+        self.lectures.iter().find(|lecture| day_of(lecture) == date)
+    }
+
+    /** Last lecture starting on `date`.
+     **/
+    pub fn last_of_day(&self, date: NaiveDate) -> Option<&Lecture> {
+        self.lectures
+            .iter()
+            .rev()
+            .find(|lecture| day_of(lecture) == date)
+    }
+
+    /** Lectures grouped by the day they start on.
+     **/
+    pub fn by_day(&self) -> BTreeMap<NaiveDate, Vec<&Lecture>> {
+        self.group_by(day_of)
+    }
+
+    /** Lectures grouped by the week they start in, keyed by the Monday of the week.
+     **/
+    pub fn by_week(&self) -> BTreeMap<NaiveDate, Vec<&Lecture>> {
+        self.group_by(|lecture| day_of(lecture).week(Weekday::Mon).first_day())
+    }
+
+    /** Lecture that is going on at `now`.
+     **/
+    pub fn current_lecture(&self, now: &DateTime<Tz>) -> Option<&Lecture> {
+        self.lectures
+            .iter()
+            .find(|lecture| lecture.period.contains(now))
+    }
+
+    /** First lecture starting after `now`.
+     **/
+    pub fn next_lecture(&self, now: &DateTime<Tz>) -> Option<&Lecture> {
+        self.lectures
+            .iter()
+            .find(|lecture| lecture.period.start_time > *now)
+    }
+
+    /** Schedule of the lectures matching `predicate`.
+     **/
+    pub fn filter(&self, predicate: impl Fn(&Lecture) -> bool) -> Schedule {
+        Self {
+            lectures: self
+                .lectures
+                .iter()
+                .filter(|lecture| predicate(lecture))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /** Schedule of the lectures of `lecture_type`.
+     **/
+    pub fn of_type(&self, lecture_type: &LectureType) -> Schedule {
+        self.filter(|lecture| lecture.lecture_type == *lecture_type)
+    }
+
+    /** Schedule of the lectures of the subject with `subject_id`.
+     **/
+    pub fn of_subject(&self, subject_id: i32) -> Schedule {
+        self.filter(|lecture| lecture.subject.id == subject_id)
+    }
+
+    /** Schedule of the lectures given by the teacher with `teacher_id`.
+     **/
+    pub fn with_teacher(&self, teacher_id: i32) -> Schedule {
+        self.filter(|lecture| {
+            lecture
+                .teachers
+                .iter()
+                .any(|teacher| teacher.id == teacher_id)
+        })
+    }
+
+    /** Schedule of the lectures attended by the group with `group_id`.
+     **/
+    pub fn with_group(&self, group_id: i32) -> Schedule {
+        self.filter(|lecture| lecture.groups.iter().any(|group| group.id == group_id))
+    }
+
+    /** Schedule of the lectures in the room named `room`, case-insensitive.
+     **/
+    pub fn in_room(&self, room: &str) -> Schedule {
+        let room = room.trim().to_lowercase();
+        self.filter(|lecture| lecture.lecture_room.trim().to_lowercase() == room)
+    }
+
+    fn group_by(&self, key: impl Fn(&Lecture) -> NaiveDate) -> BTreeMap<NaiveDate, Vec<&Lecture>> {
+        let mut groups: BTreeMap<NaiveDate, Vec<&Lecture>> = BTreeMap::new();
+        for lecture in &self.lectures {
+            groups.entry(key(lecture)).or_default().push(lecture);
+        }
+        groups
+    }
+
+    fn sort(&mut self) {
+        self.lectures.sort_by_key(order);
+    }
+}
+
+impl From<Vec<Lecture>> for Schedule {
+    fn from(lectures: Vec<Lecture>) -> Self {
+        Self::new(lectures)
+    }
+}
+
+impl From<Schedule> for Vec<Lecture> {
+    fn from(schedule: Schedule) -> Self {
+        schedule.lectures
+    }
+}
+
+impl FromIterator<Lecture> for Schedule {
+    fn from_iter<I: IntoIterator<Item = Lecture>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Lecture> for Schedule {
+    fn extend<I: IntoIterator<Item = Lecture>>(&mut self, iter: I) {
+        self.lectures.extend(iter);
+        self.sort();
+    }
+}
+
+impl IntoIterator for Schedule {
+    type Item = Lecture;
+    type IntoIter = std::vec::IntoIter<Lecture>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lectures.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Schedule {
+    type Item = &'a Lecture;
+    type IntoIter = std::slice::Iter<'a, Lecture>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lectures.iter()
+    }
+}
+
+/// Order of lectures in a [`Schedule`], by start and then by end.
+pub(crate) fn order(lecture: &Lecture) -> (DateTime<Tz>, DateTime<Tz>) {
+    (lecture.period.start_time, lecture.period.end_time)
+}
+
+fn day_of(lecture: &Lecture) -> NaiveDate {
+    lecture.period.start_time.date_naive()
+}
+
+/** Type of a [`Lecture`].
+
+Parsed from the abbreviations used by the API (case-insensitive), full Ukrainian and English names are accepted too.
+Anything unknown is kept as is in `Other`.
+
+# Variants
+ * `Lecture` - `Лк`, lecture.
+ * `Practice` - `Пз`, practical class.
+ * `Laboratory` - `Лб`, laboratory work.
+ * `Consultation` - `Конс`, consultation.
+ * `Exam` - `Екз`, exam.
+ * `Credit` - `Зал`, credit test.
+ * `Other` - any other type, with the string returned by the API.
+
+# Examples
+```
+# use nure_tools::schedule::LectureType;
+let lecture_type: LectureType = "Лб".parse().unwrap();
+
+assert_eq!(lecture_type, LectureType::Laboratory);
+assert!(lecture_type.is_lab());
+assert_eq!(lecture_type.abbreviation(), "Лб");
+assert_eq!(lecture_type.ukrainian_name(), "Лабораторна робота");
+assert_eq!(lecture_type.english_name(), "Laboratory work");
+assert_eq!(lecture_type.to_string(), "Лабораторна робота");
+
+assert_eq!(LectureType::from("екз"), LectureType::Exam);
+assert_eq!(LectureType::from("ІспКомб"), LectureType::Other(String::from("ІспКомб")));
+```
+**/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub enum LectureType {
+    Lecture,
+    Practice,
+    Laboratory,
+    Consultation,
+    Exam,
+    Credit,
+    Other(String),
+}
+
+impl LectureType {
+    const KNOWN: [(LectureType, &'static str, &'static str, &'static str); 6] = [
+        (LectureType::Lecture, "Лк", "Лекція", "Lecture"),
+        (LectureType::Practice, "Пз", "Практичне заняття", "Practice"),
+        (
+            LectureType::Laboratory,
+            "Лб",
+            "Лабораторна робота",
+            "Laboratory work",
+        ),
+        (
+            LectureType::Consultation,
+            "Конс",
+            "Консультація",
+            "Consultation",
+        ),
+        (LectureType::Exam, "Екз", "Екзамен", "Exam"),
+        (LectureType::Credit, "Зал", "Залік", "Credit"),
+    ];
+
+    /** Abbreviation used by the API, e.g. `Лк`.
+     **/
+    pub fn abbreviation(&self) -> &str {
+        self.names()
+            .map_or_else(|| self.other(), |(abbreviation, _, _)| abbreviation)
+    }
+
+    /** Full Ukrainian name, e.g. `Лекція`.
+     **/
+    pub fn ukrainian_name(&self) -> &str {
+        self.names()
+            .map_or_else(|| self.other(), |(_, ukrainian, _)| ukrainian)
+    }
+
+    /** Full English name, e.g. `Lecture`.
+     **/
+    pub fn english_name(&self) -> &str {
+        self.names()
+            .map_or_else(|| self.other(), |(_, _, english)| english)
+    }
+
+    /** Say if it is a lecture.
+     **/
+    pub fn is_lecture(&self) -> bool {
+        *self == Self::Lecture
+    }
+
+    /** Say if it is a practical class.
+     **/
+    pub fn is_practice(&self) -> bool {
+        *self == Self::Practice
+    }
+
+    /** Say if it is a laboratory work.
+     **/
+    pub fn is_lab(&self) -> bool {
+        *self == Self::Laboratory
+    }
+
+    /** Say if it is a consultation.
+     **/
+    pub fn is_consultation(&self) -> bool {
+        *self == Self::Consultation
+    }
+
+    /** Say if it is an exam.
+     **/
+    pub fn is_exam(&self) -> bool {
+        *self == Self::Exam
+    }
+
+    /** Say if it is a credit test.
+     **/
+    pub fn is_credit(&self) -> bool {
+        *self == Self::Credit
+    }
+
+    fn names(&self) -> Option<(&'static str, &'static str, &'static str)> {
+        Self::KNOWN
+            .iter()
+            .find(|(lecture_type, _, _, _)| lecture_type == self)
+            .map(|(_, abbreviation, ukrainian, english)| (*abbreviation, *ukrainian, *english))
+    }
+
+    fn other(&self) -> &str {
+        match self {
+            Self::Other(name) => name,
+            _ => "",
+        }
+    }
+}
+
+impl From<&str> for LectureType {
+    fn from(name: &str) -> Self {
+        let trimmed = name.trim().to_lowercase();
+
+        for (lecture_type, abbreviation, ukrainian, english) in Self::KNOWN {
+            if [abbreviation, ukrainian, english]
+                .iter()
+                .any(|known| known.to_lowercase() == trimmed)
+            {
+                return lecture_type;
+            }
+        }
+
+        Self::Other(String::from(name))
+    }
+}
+
+impl From<String> for LectureType {
+    fn from(name: String) -> Self {
+        Self::from(name.as_str())
+    }
+}
+
+impl From<LectureType> for String {
+    fn from(lecture_type: LectureType) -> Self {
+        match lecture_type {
+            LectureType::Other(name) => name,
+            known => String::from(known.abbreviation()),
+        }
+    }
+}
+
+impl std::str::FromStr for LectureType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(name))
+    }
+}
+
+impl std::fmt::Display for LectureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ukrainian_name())
+    }
+}
@@ -1,38 +1,99 @@
+#[cfg(feature = "async")]
+use crate::AsyncClient;
 use crate::{
-    errors::{FindError, RequestError},
-    utils::{find, get_wrapper},
+    cache::{Fetched, ResponseSource},
+    errors::{Error, FindError, ParseError, RecordError, RequestError, Result},
+    utils::find,
+    wire::{decode, decode_lenient, Decoded, TeacherRecord},
 };
-use anyhow::{anyhow, Result};
-use reqwest::blocking::get;
+#[cfg(feature = "blocking")]
+use crate::{client::default_client, Client};
 use serde_json::{self, Value};
+use std::sync::Arc;
+
+/// Path of the teachers list endpoint.
+pub(crate) const TEACHERS_PATH: &str = "/lists/teachers";
 
 /** Helper function to parse teacher json returned by API into [`Teacher`] struct.
 
 You probably will never use it, but you can if you want, see example in [`get_teachers`] function source
+
+# Errors
+This function fails if:
+ * `ParseError::InvalidRecord` - Any of the records is not a valid teacher.
 **/
-pub fn parse_teacher_json(vector: Vec<Value>) -> Vec<Teacher> {
-    let mut result: Vec<Teacher> = Vec::new();
+pub fn parse_teacher_json(vector: Vec<Value>) -> Result<Vec<Teacher>> {
+    Ok(decode::<TeacherRecord>(vector, false)?.items)
+}
 
-    let mut id: i32 = 0;
-    let mut short_name: String = String::new();
-    let mut long_name: String = String::new();
+/** Helper function to parse teacher json returned by API into [`Teacher`] struct, skipping invalid records.
 
-    for element in vector {
-        if let Value::Object(obj) = element {
-            if let Some(Value::Number(n)) = obj.get("id") {
-                id = n.as_i64().unwrap_or(0) as i32;
-            }
-            if let Some(Value::String(st)) = obj.get("shortName") {
-                short_name = st.clone();
-            }
-            if let Some(Value::String(st)) = obj.get("fullName") {
-                long_name = st.clone();
-            }
-            result.push(Teacher::new(id, short_name.clone(), long_name.clone()));
-        };
+Same as [`parse_teacher_json`], but returns the skipped records as warnings instead of failing.
+**/
+pub fn parse_teacher_json_lenient(vector: Vec<Value>) -> (Vec<Teacher>, Vec<RecordError>) {
+    let decoded = decode_lenient::<TeacherRecord>(vector);
+    (decoded.items, decoded.warnings)
+}
+
+/** Helper function to turn the teachers list endpoint response into `Vec<Teacher>`.
+
+In lenient mode invalid records are skipped and returned as warnings.
+**/
+pub(crate) fn teachers_from_response(response: Value, lenient: bool) -> Result<Decoded<Teacher>> {
+    if let Value::Array(vector) = response {
+        decode::<TeacherRecord>(vector, lenient)
+    } else {
+        Err(Error::from(RequestError::InvalidReturn))
+    }
+}
+
+/** Helper function to select all teachers that match `name`.
+**/
+pub(crate) fn filter_teachers(teachers: &[Teacher], name: &str) -> Result<Vec<Teacher>> {
+    let mut result: Vec<Teacher> = vec![];
+
+    for teacher in teachers {
+        if find(name, &teacher.full_name)? {
+            result.push(teacher.clone());
+        } else {
+            continue;
+        }
+    }
+
+    if result.is_empty() {
+        Err(Error::from(FindError::InvalidTeacherName(String::from(
+            name,
+        ))))
+    } else {
+        Ok(result)
+    }
+}
+
+/** Helper function to select the teacher named exectly `name`.
+
+Falls back to the only teacher whose [`TeacherName`] matches `name`, if there is exactly one.
+**/
+pub(crate) fn exect_teacher(teachers: &[Teacher], name: &str) -> Result<Teacher> {
+    for teacher in teachers {
+        if name.to_lowercase() == teacher.short_name.to_lowercase() {
+            return Ok(teacher.clone());
+        } else {
+            continue;
+        }
     }
 
-    result
+    if let Ok(query) = name.parse::<TeacherName>() {
+        let mut matched = teachers
+            .iter()
+            .filter(|teacher| teacher.name().matches(&query));
+        if let (Some(teacher), None) = (matched.next(), matched.next()) {
+            return Ok(teacher.clone());
+        }
+    }
+
+    Err(Error::from(FindError::InvalidTeacherName(String::from(
+        name,
+    ))))
 }
 
 /** Get all existing teachers.
@@ -42,7 +103,7 @@ Returns all existing teachers in `Vec<Teacher>` format.
 # Examples
 ```
 # use nure_tools::teachers::{get_teachers, Teacher};
-# use anyhow::Error;
+# use nure_tools::Error;
 let teachers: Vec<Teacher> = get_teachers()?;
 println!("{:#?}", teachers);
 # Ok::<(), Error>(())
@@ -50,19 +111,15 @@ println!("{:#?}", teachers);
 
 # Errors
 This function fails if:
- * `RequestError::GetFailed` - Get request fails.
- * `RequestError::NotJson` - Server returns value not in json format.
+ * `Error::Transport` - Get request fails.
+ * `Error::Decode` - Server returns value not in json format.
  * `RequestError::BadResponse` - Server returns any response except 200.
  * `RequestError::InvalidReturn` - Server returns value in unexpected format.
+ * `ParseError::InvalidRecord` - Any of the returned teachers is invalid.
 **/
+#[cfg(feature = "blocking")]
 pub fn get_teachers() -> Result<Vec<Teacher>> {
-    let response = get_wrapper(get("https://api.mindenit.tech/lists/teachers"))?;
-    if let Value::Array(vector) = response {
-        let result: Vec<Teacher> = parse_teacher_json(vector);
-        Ok(result)
-    } else {
-        Err(anyhow!(RequestError::InvalidReturn))
-    }
+    default_client()?.get_teachers()
 }
 
 /** Find a Teacher by name.
@@ -75,7 +132,7 @@ Returns all matched teachers in `Vec<Teacher>` format.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::teachers::{find_teacher, Teacher};
 let teacher: Vec<Teacher> = find_teacher("Новіков")?;
 println!("teachers: {:#?}\n", teacher);
@@ -91,36 +148,26 @@ This function fails if:
  * [`get_teachers`] fails.
  * [`find`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_teacher(name: &str) -> Result<Vec<Teacher>> {
-    let teachers = get_teachers()?;
-    let mut result: Vec<Teacher> = vec![];
-
-    for teacher in teachers {
-        if find(name, &teacher.full_name)? {
-            result.push(teacher);
-        } else {
-            continue;
-        }
-    }
-
-    if result.is_empty() {
-        Err(anyhow!(FindError::InvalidTeacherName(String::from(name))))
-    } else {
-        Ok(result)
-    }
+    default_client()?.find_teacher(name)
 }
 
 /** Find exect teacher.
 
 Returns 1 exect matched teacher.
 
+The name is compared with the short name of every teacher first,
+then it is matched as a [`TeacherName`], so `"Терещенко Гліб"`, `"Терещенко Г.Ю."` and `"терещенко г ю"`
+all find the same teacher as long as only one teacher matches.
+
 # Arguments
 
 * `name` - &str with teacher name to search for.
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::teachers::{find_exect_teacher, Teacher};
 let teacher: Teacher = find_exect_teacher("Терещенко Г. Ю.")?;
 println!("teacher: {:#?}", teacher);
@@ -132,23 +179,122 @@ This function fails if:
  * `FindError::InvalidTeacherName(name)` - There is no teacher that matches given name.
  * [`get_teachers`] fails.
 **/
+#[cfg(feature = "blocking")]
 pub fn find_exect_teacher(name: &str) -> Result<Teacher> {
-    let teacher = get_teachers()?;
+    default_client()?.find_exect_teacher(name)
+}
 
-    for teacher in teacher {
-        if name.to_lowercase() == teacher.short_name.to_lowercase() {
-            return Ok(teacher);
-        } else {
-            continue;
+#[cfg(feature = "blocking")]
+impl Client {
+    /** Get all existing teachers.
+
+    Same as [`get_teachers`], but uses this client.
+    The list is served from the directory cache of the client while it is fresh.
+    **/
+    pub fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        Ok(self.teachers_directory()?.data.to_vec())
+    }
+
+    /** Get all existing teachers and say where they came from.
+
+    Same as [`Client::get_teachers`], but also returns the [`ResponseSource`].
+    **/
+    pub fn fetch_teachers(&self) -> Result<Fetched<Vec<Teacher>>> {
+        let fetched = self.teachers_directory()?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a Teacher by name.
+
+    Same as [`find_teacher`], but uses this client.
+    **/
+    pub fn find_teacher(&self, name: &str) -> Result<Vec<Teacher>> {
+        filter_teachers(&self.teachers_directory()?.data, name)
+    }
+
+    /** Find exect teacher.
+
+    Same as [`find_exect_teacher`], but uses this client.
+    **/
+    pub fn find_exect_teacher(&self, name: &str) -> Result<Teacher> {
+        exect_teacher(&self.teachers_directory()?.data, name)
+    }
+
+    pub(crate) fn teachers_directory(&self) -> Result<Fetched<Arc<Vec<Teacher>>>> {
+        match self.directory.teachers.get() {
+            Some(teachers) => Ok(Fetched::new(teachers, ResponseSource::FreshCache)),
+            None => self.refresh_teachers(),
         }
     }
 
-    Err(anyhow!(FindError::InvalidTeacherName(String::from(name))))
+    pub(crate) fn refresh_teachers(&self) -> Result<Fetched<Arc<Vec<Teacher>>>> {
+        let fetched = self.fetch_json(TEACHERS_PATH, &[])?;
+        let decoded = teachers_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory.teachers.set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /** Get all existing teachers.
+
+    Async counterpart of [`Client::get_teachers`].
+    **/
+    pub async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        Ok(self.teachers_directory().await?.data.to_vec())
+    }
+
+    /** Get all existing teachers and say where they came from.
+
+    Async counterpart of [`Client::fetch_teachers`].
+    **/
+    pub async fn fetch_teachers(&self) -> Result<Fetched<Vec<Teacher>>> {
+        let fetched = self.teachers_directory().await?;
+        Ok(Fetched::new(fetched.data.to_vec(), fetched.source).with_warnings(fetched.warnings))
+    }
+
+    /** Find a Teacher by name.
+
+    Async counterpart of [`Client::find_teacher`].
+    **/
+    pub async fn find_teacher(&self, name: &str) -> Result<Vec<Teacher>> {
+        filter_teachers(&self.teachers_directory().await?.data, name)
+    }
+
+    /** Find exect teacher.
+
+    Async counterpart of [`Client::find_exect_teacher`].
+    **/
+    pub async fn find_exect_teacher(&self, name: &str) -> Result<Teacher> {
+        exect_teacher(&self.teachers_directory().await?.data, name)
+    }
+
+    pub(crate) async fn teachers_directory(&self) -> Result<Fetched<Arc<Vec<Teacher>>>> {
+        match self.directory.teachers.get() {
+            Some(teachers) => Ok(Fetched::new(teachers, ResponseSource::FreshCache)),
+            None => self.refresh_teachers().await,
+        }
+    }
+
+    pub(crate) async fn refresh_teachers(&self) -> Result<Fetched<Arc<Vec<Teacher>>>> {
+        let fetched = self.fetch_json(TEACHERS_PATH, &[]).await?;
+        let decoded = teachers_from_response(fetched.data, self.lenient)?;
+        Ok(Fetched::new(
+            self.directory.teachers.set(decoded.items, fetched.source),
+            fetched.source,
+        )
+        .with_warnings(decoded.warnings))
+    }
 }
 
 /** Teacher struct.
 **/
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Teacher {
     pub id: i32,
     pub short_name: String,
@@ -156,13 +302,53 @@ pub struct Teacher {
 }
 
 impl Teacher {
-    fn new(id: i32, short_name: String, full_name: String) -> Self {
+    pub(crate) fn new(id: i32, short_name: String, full_name: String) -> Self {
         Self {
             id,
             short_name,
             full_name,
         }
     }
+
+    /** [`TeacherName`] parsed from the full name, or from the short name if the full one is empty.
+     **/
+    pub fn name(&self) -> TeacherName {
+        self.full_name
+            .parse()
+            .or_else(|_| self.short_name.parse())
+            .unwrap_or_default()
+    }
+}
+
+/** Sort teachers alphabetically by surname, first name and patronymic with Ukrainian collation.
+
+# Examples
+```
+# use nure_tools::{
+#     teachers::{sort_teachers, Teacher},
+#     transport::FixtureTransport,
+#     Client, Error,
+# };
+# let transport = FixtureTransport::new().with_json(
+#     "/lists/teachers",
+#     r#"[
+#         {"id": 1, "fullName": "Ющенко Олена Петрівна", "shortName": "Ющенко О. П."},
+#         {"id": 2, "fullName": "Іванов Іван Іванович", "shortName": "Іванов І. І."},
+#         {"id": 3, "fullName": "Гончар Анна Сергіївна", "shortName": "Гончар А. С."},
+#         {"id": 4, "fullName": "Єрмак Ігор Олегович", "shortName": "Єрмак І. О."}
+#     ]"#,
+# );
+# let client = Client::builder().transport(transport).build()?;
+let mut teachers: Vec<Teacher> = client.get_teachers()?;
+sort_teachers(&mut teachers);
+
+let surnames: Vec<String> = teachers.iter().map(|teacher| teacher.name().surname).collect();
+assert_eq!(surnames, ["Гончар", "Єрмак", "Іванов", "Ющенко"]);
+# Ok::<(), Error>(())
+```
+**/
+pub fn sort_teachers(teachers: &mut [Teacher]) {
+    teachers.sort_by_cached_key(|teacher| teacher.name());
 }
 
 impl std::fmt::Display for Teacher {
@@ -170,3 +356,247 @@ impl std::fmt::Display for Teacher {
         write!(f, "{}", self.full_name)
     }
 }
+
+/** Parsed teacher name.
+
+Parsed from `"Surname FirstName Patronymic"` with any of the parts after the surname optional.
+Dots and whitespace separate the parts, so `"Терещенко Гліб Юрійович"`, `"Терещенко Г. Ю."` and `"терещенко г.ю."` are all accepted.
+A part of a single letter is an initial.
+
+Names are ordered by surname, first name and patronymic with Ukrainian collation.
+
+# Examples
+```
+# use nure_tools::{teachers::TeacherName, Error};
+let name: TeacherName = "Терещенко Гліб Юрійович".parse()?;
+
+assert_eq!(name.surname, "Терещенко");
+assert_eq!(name.initials(), "Г. Ю.");
+assert_eq!(name.short_name(), "Терещенко Г. Ю.");
+assert_eq!(name.initials_first(), "Г. Ю. Терещенко");
+assert_eq!(name.to_string(), "Терещенко Гліб Юрійович");
+
+assert!(name.matches(&"Терещенко Гліб".parse()?));
+assert!(name.matches(&"Терещенко Г.Ю.".parse()?));
+assert!(name.matches(&"терещенко г ю".parse()?));
+assert!(name.matches(&"Терещенка Гліба".parse()?));
+assert!(!name.matches(&"Терещенко О. Ю.".parse()?));
+assert!(!name.matches(&"Т".parse()?));
+
+let name: TeacherName = "Іванов Олег".parse()?;
+assert!(!name.matches(&"Іванова Олена".parse()?));
+assert!(!name.matches(&"Іванова".parse()?));
+assert!(name.matches(&"Іванову".parse()?));
+# Ok::<(), Error>(())
+```
+
+# Errors
+Parsing fails with [`ParseError::InvalidTeacherName`] if the string contains no name at all.
+
+[`ParseError::InvalidTeacherName`]: `crate::errors::ParseError::InvalidTeacherName`
+**/
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TeacherName {
+    pub surname: String,
+    pub first_name: Option<String>,
+    pub patronymic: Option<String>,
+}
+
+impl TeacherName {
+    /** Initials of the first name and the patronymic, e.g. `Г. Ю.`.
+     **/
+    pub fn initials(&self) -> String {
+        [&self.first_name, &self.patronymic]
+            .into_iter()
+            .flatten()
+            .filter_map(|part| part.chars().next())
+            .map(|initial| format!("{}.", initial))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /** Surname followed by the initials, e.g. `Терещенко Г. Ю.`.
+     **/
+    pub fn short_name(&self) -> String {
+        join_name(&[self.surname.clone(), self.initials()])
+    }
+
+    /** Initials followed by the surname, e.g. `Г. Ю. Терещенко`.
+     **/
+    pub fn initials_first(&self) -> String {
+        join_name(&[self.initials(), self.surname.clone()])
+    }
+
+    /** Full name, e.g. `Терещенко Гліб Юрійович`, initials are kept as initials.
+     **/
+    pub fn full_name(&self) -> String {
+        let mut parts: Vec<String> = vec![self.surname.clone()];
+        for part in [&self.first_name, &self.patronymic].into_iter().flatten() {
+            if part.chars().count() == 1 {
+                parts.push(format!("{}.", part));
+            } else {
+                parts.push(part.clone());
+            }
+        }
+
+        join_name(&parts)
+    }
+
+    /** Say if both names may belong to the same person.
+
+    Surnames must match and have at least two letters, first names and patronymics are compared
+    only if both names have them, by the initial if any of them is an initial.
+    Comparison ignores case, apostrophe variants and the most common case endings,
+    so declined forms like `Терещенка Гліба` still match, while feminine surnames like `Іванова`
+    stay apart from the masculine `Іванов`.
+    **/
+    pub fn matches(&self, other: &TeacherName) -> bool {
+        same_surname(&self.surname, &other.surname)
+            && same_optional_part(&self.first_name, &other.first_name)
+            && same_optional_part(&self.patronymic, &other.patronymic)
+    }
+}
+
+impl std::str::FromStr for TeacherName {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        let mut parts = name
+            .split(|c: char| c.is_whitespace() || c == '.')
+            .filter(|part| !part.is_empty())
+            .map(String::from);
+
+        match parts.next() {
+            Some(surname) => Ok(Self {
+                surname,
+                first_name: parts.next(),
+                patronymic: parts.next(),
+            }),
+            None => Err(Error::from(ParseError::InvalidTeacherName(String::from(
+                name,
+            )))),
+        }
+    }
+}
+
+impl std::fmt::Display for TeacherName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_name())
+    }
+}
+
+impl Ord for TeacherName {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |name: &TeacherName| {
+            (
+                collation_key(&name.surname),
+                collation_key(name.first_name.as_deref().unwrap_or("")),
+                collation_key(name.patronymic.as_deref().unwrap_or("")),
+            )
+        };
+
+        key(self).cmp(&key(other)).then_with(|| {
+            (&self.surname, &self.first_name, &self.patronymic).cmp(&(
+                &other.surname,
+                &other.first_name,
+                &other.patronymic,
+            ))
+        })
+    }
+}
+
+impl PartialOrd for TeacherName {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Ukrainian alphabet in collation order.
+const UKRAINIAN_ALPHABET: &str = "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя";
+
+/// Case endings stripped before comparing declined name parts, longest first.
+const CASE_ENDINGS: [&str; 23] = [
+    "ові", "еві", "єві", "ом", "ем", "єм", "ою", "ею", "єю", "ої", "еї", "єї", "ій", "а", "я", "у",
+    "ю", "і", "и", "о", "е", "є", "й",
+];
+
+/// Stems of feminine surnames that keep their `-а`/`-я` ending, e.g. `Іванова` or `Ковальська`.
+const FEMININE_SUFFIXES: [&str; 8] = ["ов", "ев", "єв", "ін", "їн", "ськ", "цьк", "зьк"];
+
+fn join_name(parts: &[String]) -> String {
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn normalize_name_part(part: &str) -> String {
+    part.to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '\'' | '’' | 'ʼ' | '`'))
+        .collect()
+}
+
+fn stem(part: &str) -> &str {
+    for ending in CASE_ENDINGS {
+        if let Some(stem) = part.strip_suffix(ending) {
+            if stem.chars().count() < 3 || is_feminine_ending(stem, ending) {
+                continue;
+            }
+
+            return stem;
+        }
+    }
+
+    part
+}
+
+/// Helper function to say if stripping `ending` would turn a feminine surname like `Іванова` into a masculine one.
+fn is_feminine_ending(stem: &str, ending: &str) -> bool {
+    matches!(ending, "а" | "я")
+        && FEMININE_SUFFIXES
+            .iter()
+            .any(|suffix| stem.ends_with(suffix))
+}
+
+fn same_surname(left: &str, right: &str) -> bool {
+    let (left, right) = (normalize_name_part(left), normalize_name_part(right));
+
+    if left.chars().count() < 2 || right.chars().count() < 2 {
+        false
+    } else {
+        left == right || stem(&left) == stem(&right)
+    }
+}
+
+fn same_name_part(left: &str, right: &str) -> bool {
+    let (left, right) = (normalize_name_part(left), normalize_name_part(right));
+
+    if left.chars().count() == 1 || right.chars().count() == 1 {
+        left.chars().next() == right.chars().next()
+    } else {
+        left == right || stem(&left) == stem(&right)
+    }
+}
+
+fn same_optional_part(left: &Option<String>, right: &Option<String>) -> bool {
+    match (left, right) {
+        (Some(left), Some(right)) => same_name_part(left, right),
+        _ => true,
+    }
+}
+
+fn collation_key(part: &str) -> Vec<(u8, u32)> {
+    normalize_name_part(part)
+        .chars()
+        .map(
+            |c| match UKRAINIAN_ALPHABET.chars().position(|letter| letter == c) {
+                Some(position) => (0, position as u32),
+                None => (1, c as u32),
+            },
+        )
+        .collect()
+}
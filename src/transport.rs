@@ -0,0 +1,415 @@
+use crate::errors::{Error, RequestError, Result};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "blocking")]
+use reqwest::blocking;
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
+/** Request sent through a [`Transport`].
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportRequest {
+    pub path: String,
+    pub query: Vec<(String, String)>,
+}
+
+impl TransportRequest {
+    /** Create a new TransportRequest for `path` of the API with the given query pairs.
+     **/
+    pub fn new(path: &str, query: &[(&str, String)]) -> Self {
+        Self {
+            path: String::from(path),
+            query: query
+                .iter()
+                .map(|(key, value)| (String::from(*key), value.clone()))
+                .collect(),
+        }
+    }
+}
+
+/** Raw response returned by a [`Transport`].
+**/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl TransportResponse {
+    /** Create a new TransportResponse without headers.
+     **/
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /** Add a header to the response.
+     **/
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /** Value of the first header named `name`, case-insensitive.
+     **/
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /** Delay requested by the `Retry-After` header, if any.
+
+    Supports both delay in seconds and HTTP-date formats.
+    **/
+    pub fn retry_after(&self) -> Option<Duration> {
+        let value = self.header("retry-after")?.trim();
+
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            date.with_timezone(&Utc)
+                .signed_duration_since(Utc::now())
+                .to_std()
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
+    /** Helper function to turn the response into json.
+
+    # Errors
+    This function fails if:
+     * [`Error::RateLimited`] - Status code is 429.
+     * [`RequestError::BadResponse`] - Status code is anything else except 200.
+     * [`Error::Decode`] - Body is not in json format.
+    **/
+    pub fn into_json(self) -> Result<Value> {
+        self.json()
+    }
+
+    /** Helper function to parse the response as json without consuming it.
+
+    Same as [`into_json`].
+
+    [`into_json`]: `TransportResponse::into_json`
+    **/
+    pub fn json(&self) -> Result<Value> {
+        match self.status {
+            200 => Ok(serde_json::from_str::<Value>(&self.body)?),
+            429 => Err(Error::RateLimited {
+                retry_after: self.retry_after(),
+            }),
+            status => Err(Error::from(RequestError::BadResponse(
+                String::from(
+                    reqwest::StatusCode::from_u16(status)
+                        .ok()
+                        .and_then(|status| status.canonical_reason())
+                        .unwrap_or(""),
+                ),
+                status,
+            ))),
+        }
+    }
+}
+
+/** HTTP transport used by a [`Client`] for every call.
+
+Implement it to send requests through anything other than the default [`ReqwestTransport`],
+or use the in-memory [`FixtureTransport`] in tests.
+
+[`Client`]: `crate::Client`
+**/
+#[cfg(feature = "blocking")]
+pub trait Transport: Send + Sync {
+    /** Send a Get request and return the raw response.
+
+    # Errors
+    Should fail only if no response was received at all, non 200 status codes are handled by the client.
+    Use [`Error::Timeout`] for timeouts and [`Error::Transport`] for everything else, keeping the underlying error as the source.
+    **/
+    fn get(&self, request: &TransportRequest) -> Result<TransportResponse>;
+}
+
+/** Boxed future returned by an [`AsyncTransport`].
+**/
+#[cfg(feature = "async")]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse>> + Send + 'a>>;
+
+/** Async HTTP transport used by an [`AsyncClient`] for every call.
+
+Async counterpart of [`Transport`].
+
+[`AsyncClient`]: `crate::AsyncClient`
+**/
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Send + Sync {
+    /** Send a Get request and return the raw response.
+
+    # Errors
+    Should fail only if no response was received at all, non 200 status codes are handled by the client.
+    Use [`Error::Timeout`] for timeouts and [`Error::Transport`] for everything else, keeping the underlying error as the source.
+    **/
+    fn get<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a>;
+}
+
+/** Default blocking [`Transport`] backed by `reqwest`.
+**/
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    base_url: String,
+    http: blocking::Client,
+}
+
+#[cfg(feature = "blocking")]
+impl ReqwestTransport {
+    /** Create a new ReqwestTransport sending requests to `base_url` with the given client.
+     **/
+    pub fn new(base_url: &str, http: blocking::Client) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReqwestTransport {
+    fn get(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        let response = match self
+            .http
+            .get(format!("{}{}", self.base_url, request.path))
+            .query(&request.query)
+            .send()
+        {
+            Ok(response) => response,
+            Err(error) => return Err(send_error(error)),
+        };
+
+        let status = response.status().as_u16();
+        let headers = collect_headers(response.headers());
+        match response.text() {
+            Ok(body) => Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            }),
+            Err(error) => Err(send_error(error)),
+        }
+    }
+}
+
+/** Default [`AsyncTransport`] backed by `reqwest`.
+**/
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct AsyncReqwestTransport {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    /** Create a new AsyncReqwestTransport sending requests to `base_url` with the given client.
+     **/
+    pub fn new(base_url: &str, http: reqwest::Client) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for AsyncReqwestTransport {
+    fn get<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = match self
+                .http
+                .get(format!("{}{}", self.base_url, request.path))
+                .query(&request.query)
+                .send()
+                .await
+            {
+                Ok(response) => response,
+                Err(error) => return Err(send_error(error)),
+            };
+
+            let status = response.status().as_u16();
+            let headers = collect_headers(response.headers());
+            match response.text().await {
+                Ok(body) => Ok(TransportResponse {
+                    status,
+                    headers,
+                    body,
+                }),
+                Err(error) => Err(send_error(error)),
+            }
+        })
+    }
+}
+
+/** Helper function to turn a `reqwest` error into [`Error::Timeout`] or [`Error::Transport`].
+**/
+pub(crate) fn send_error(error: reqwest::Error) -> Error {
+    if error.is_timeout() {
+        Error::Timeout(Box::new(error))
+    } else {
+        Error::Transport(Box::new(error))
+    }
+}
+
+fn collect_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/** In-memory transport serving canned responses.
+
+Responses are matched by request path only, the query is ignored.
+If several responses are registered for the same path, they are returned in order and the last one is repeated.
+Unknown paths are answered with `404`.
+
+Cloned instances share their fixtures and the log of received requests.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::{
+#     groups::Group,
+#     schedule::{Lecture, Request},
+#     transport::FixtureTransport,
+#     utils::Period,
+#     Client,
+# };
+let transport = FixtureTransport::new()
+    .with_json("/lists/groups", r#"[{"id": 10887035, "name": "ПЗПІ-23-2"}]"#)
+    .with_json(
+        "/schedule/groups/10887035",
+        r#"[{
+            "id": 1,
+            "startTime": 1704175200,
+            "endTime": 1704180900,
+            "auditory": "287",
+            "numberPair": 2,
+            "type": "Лк",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "groups": [{"id": 10887035, "name": "ПЗПІ-23-2"}],
+            "teachers": [{"id": 1, "fullName": "Терещенко Гліб Юрійович", "shortName": "Терещенко Г. Ю."}],
+            "subject": {"id": 2, "title": "Об'єктно-орієнтоване програмування", "brief": "ООП"}
+        }]"#,
+    );
+
+let client = Client::builder().transport(transport.clone()).build()?;
+
+let group: Group = client.find_exect_group("пзпі-23-2")?;
+let schedule: Vec<Lecture> = client.get_schedule(
+    Request::Group(group),
+    Period::from_string("2024-01-02", "2024-01-03")?,
+)?;
+
+assert_eq!(schedule[0].subject.brief, "ООП");
+assert_eq!(transport.requests().len(), 2);
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, Default)]
+pub struct FixtureTransport {
+    state: Arc<Mutex<FixtureState>>,
+}
+
+#[derive(Debug, Default)]
+struct FixtureState {
+    responses: HashMap<String, VecDeque<TransportResponse>>,
+    requests: Vec<TransportRequest>,
+}
+
+impl FixtureTransport {
+    /** Create a new FixtureTransport without any fixtures.
+     **/
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /** Register a `200` response with the given json body for `path`.
+     **/
+    pub fn with_json(self, path: &str, body: impl Into<String>) -> Self {
+        self.with_response(path, TransportResponse::new(200, body))
+    }
+
+    /** Register a response for `path`.
+     **/
+    pub fn with_response(self, path: &str, response: TransportResponse) -> Self {
+        self.push_response(path, response);
+        self
+    }
+
+    /** Register a response for `path` without consuming the transport.
+     **/
+    pub fn push_response(&self, path: &str, response: TransportResponse) {
+        self.lock()
+            .responses
+            .entry(String::from(path))
+            .or_default()
+            .push_back(response);
+    }
+
+    /** All requests received so far, in order.
+     **/
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.lock().requests.clone()
+    }
+
+    fn respond(&self, request: &TransportRequest) -> TransportResponse {
+        let mut state = self.lock();
+        state.requests.push(request.clone());
+
+        match state.responses.get_mut(&request.path) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => TransportResponse::new(404, ""),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FixtureState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for FixtureTransport {
+    fn get(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        Ok(self.respond(request))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for FixtureTransport {
+    fn get<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        Box::pin(async move { Ok(self.respond(request)) })
+    }
+}
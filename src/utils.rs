@@ -1,30 +1,167 @@
-use crate::errors::{FindError, ParseError, RequestError};
-use anyhow::{anyhow, Result};
-use chrono::{DateTime, Duration, Utc};
+use crate::{
+    calendar::{
+        end_of, today_in, upcoming_semester, upcoming_session, AcademicCalendar, DateRange,
+        Semester,
+    },
+    errors::{Error, FindError, ParseError, Result},
+    natural::parse_period,
+};
+#[cfg(feature = "blocking")]
+use crate::{errors::RequestError, transport::send_error};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
 use chrono_tz::Tz::{self, Europe__Kiev};
-use dateparser::parse;
-use now::DateTimeNow;
+use dateparser::parse_with_timezone;
+
 use regex::Regex;
+#[cfg(feature = "blocking")]
 use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
 use serde_json::Value;
-use std::fmt;
+use std::{
+    cmp::{max, min},
+    fmt,
+};
+
+/// Time zone of the university, used by every constructor and client without an explicit time zone.
+pub const DEFAULT_TIME_ZONE: Tz = Europe__Kiev;
+
+/// Earliest year a [`Period`] can start in.
+pub const MIN_YEAR: i32 = 2000;
+
+/// Latest year a [`Period`] can end in.
+pub const MAX_YEAR: i32 = 2100;
 
 /** Period struct
+
+# Serialization
+With the `serde` feature a Period is (de)serialized as a struct with RFC 3339 `start_time` and `end_time`
+(including the UTC offset) and the IANA name of their `time_zone`, deserialized periods are checked with [`Period::validate`]:
+```json
+{"start_time": "2024-01-02T00:00:00+02:00", "end_time": "2024-01-03T00:00:00+02:00", "time_zone": "Europe/Kiev"}
+```
+
+```
+# use nure_tools::Error;
+# use nure_tools::utils::Period;
+# #[cfg(feature = "serde")]
+# {
+let period: Period = Period::from_string("2024-01-02", "2024-01-03")?;
+
+let json: String = serde_json::to_string(&period)?;
+let restored: Period = serde_json::from_str(&json)?;
+
+assert_eq!(restored.start_time, period.start_time);
+assert_eq!(restored.end_time, period.end_time);
+assert_eq!(restored.start_time.timezone(), period.start_time.timezone());
+
+let inverted = r#"{"start_time": "2024-01-03T00:00:00+02:00", "end_time": "2024-01-02T00:00:00+02:00", "time_zone": "Europe/Kiev"}"#;
+assert!(serde_json::from_str::<Period>(inverted).is_err());
+# }
+# Ok::<(), Error>(())
+```
 **/
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Period {
     pub start_time: DateTime<Tz>,
     pub end_time: DateTime<Tz>,
 }
 
 impl Period {
+    /** Create a new Period instance from chrono values.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    use chrono::TimeZone;
+    use chrono_tz::Tz::Europe__Kiev;
+
+    let start_time = Europe__Kiev.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+    let end_time = Europe__Kiev.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+
+    let period: Period = Period::new(start_time, end_time)?;
+    assert!(Period::new(end_time, start_time).is_err());
+    # Ok::<(), Error>(())
+    ```
+    # Errors
+    This function fails if:
+     * [`ParseError::InvertedPeriod`] - `end_time` is not after `start_time`.
+     * [`ParseError::YearOutOfRange`] - Any of the times is not between [`MIN_YEAR`] and [`MAX_YEAR`].
+    **/
+    pub fn new(start_time: DateTime<Tz>, end_time: DateTime<Tz>) -> Result<Self> {
+        let period = Self {
+            start_time,
+            end_time,
+        };
+        period.validate()?;
+        Ok(period)
+    }
+
+    /** Check that the Period ends after it starts, within [`MIN_YEAR`] and [`MAX_YEAR`].
+
+    Every constructor checks it already, but the fields are public and can be changed afterwards.
+
+    # Errors
+    This function fails if:
+     * [`ParseError::InvertedPeriod`] - Period doesn't end after it starts.
+     * [`ParseError::YearOutOfRange`] - Any of the times is not between [`MIN_YEAR`] and [`MAX_YEAR`].
+    **/
+    pub fn validate(&self) -> Result<()> {
+        for time in [&self.start_time, &self.end_time] {
+            if !(MIN_YEAR..=MAX_YEAR).contains(&time.year()) {
+                return Err(Error::from(ParseError::YearOutOfRange(time.year())));
+            }
+        }
+
+        if self.end_time <= self.start_time {
+            return Err(Error::from(ParseError::InvertedPeriod(self.to_string())));
+        }
+
+        Ok(())
+    }
+
+    /** Same as [`Period::validate`], but also checks that the Period is not longer than `max_span`.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    use chrono::Duration;
+
+    let period: Period = Period::from_string("2024-01-01", "2024-12-31")?;
+
+    assert!(period.validate_span(Duration::days(366)).is_ok());
+    assert!(period.validate_span(Duration::weeks(1)).is_err());
+
+    let error = period.validate_span(Duration::minutes(90)).unwrap_err();
+    assert!(error.to_string().starts_with("Period is longer than 1h 30m"));
+    # Ok::<(), Error>(())
+    ```
+    # Errors
+    This function fails if:
+     * [`Period::validate`] fails.
+     * [`ParseError::PeriodTooLong`] - Period is longer than `max_span`.
+    **/
+    pub fn validate_span(&self, max_span: Duration) -> Result<()> {
+        self.validate()?;
+
+        if self.duration() > max_span {
+            return Err(Error::from(ParseError::PeriodTooLong(
+                self.to_string(),
+                format_span(max_span),
+            )));
+        }
+
+        Ok(())
+    }
+
     /** Create a new Period instance from a given String representations of a DateTime.
 
     Create a new Period instance from a given String representations of a DateTime.
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let start_time: &str = "2024-01-02";
     let end_time: &str = "January 3, 2024";
@@ -37,36 +174,56 @@ impl Period {
     # Errors
     This function fails if:
         [`ParseError::InvalidStringProvided`] - Can't parse datetime from given string.
+        [`Period::new`] fails.
     */
     pub fn from_string(start_time_str: &str, end_time_str: &str) -> Result<Self> {
-        let start_time: DateTime<Tz> = match parse(start_time_str) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
+        Self::from_string_in(start_time_str, end_time_str, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::from_string`], but in `time_zone`.
+
+    Strings without an UTC offset are read as local time of `time_zone`.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    use chrono_tz::Tz;
+
+    let kyiv: Period = Period::from_string("2024-01-02 10:00", "2024-01-02 12:00")?;
+    let utc: Period = Period::from_string_in("2024-01-02 08:00", "2024-01-02 10:00", Tz::UTC)?;
+
+    assert_eq!(utc, kyiv);
+    assert_eq!(utc.start_time.to_string(), "2024-01-02 08:00:00 UTC");
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn from_string_in(start_time_str: &str, end_time_str: &str, time_zone: Tz) -> Result<Self> {
+        let start_time: DateTime<Tz> = match parse_with_timezone(start_time_str, &time_zone) {
+            Ok(parsed) => parsed.with_timezone(&time_zone),
             Err(_) => {
-                return Err(anyhow!(ParseError::InvalidStringProvided(String::from(
-                    start_time_str
-                ))));
+                return Err(Error::from(ParseError::InvalidStringProvided(
+                    String::from(start_time_str),
+                )));
             }
         };
-        let end_time: DateTime<Tz> = match parse(end_time_str) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
+        let end_time: DateTime<Tz> = match parse_with_timezone(end_time_str, &time_zone) {
+            Ok(parsed) => parsed.with_timezone(&time_zone),
             Err(_) => {
-                return Err(anyhow!(ParseError::InvalidStringProvided(String::from(
-                    end_time_str
-                ))));
+                return Err(Error::from(ParseError::InvalidStringProvided(
+                    String::from(end_time_str),
+                )));
             }
         };
 
-        Ok(Self {
-            start_time,
-            end_time,
-        })
+        Self::new(start_time, end_time)
     }
 
     /** Create a new Period instance from a given timestamp representations of a DateTime
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let start_time: i64 = 1704146400;
     let end_time: i64 = 1704232800;
@@ -79,38 +236,37 @@ impl Period {
     # Errors
     This function fails if:
         [`ParseError::InvalidTimestampProvided`] - Can't parse datetime from given timestamp.
+        [`Period::new`] fails.
     */
     pub fn from_timestamp(start_time_i64: i64, end_time_i64: i64) -> Result<Self> {
-        let start_time_i64 = start_time_i64.to_string();
-        let end_time_i64 = end_time_i64.to_string();
+        Self::from_timestamp_in(start_time_i64, end_time_i64, DEFAULT_TIME_ZONE)
+    }
 
-        let start_time: DateTime<Tz> = match parse(&start_time_i64) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
-            Err(_) => {
-                return Err(anyhow!(ParseError::InvalidTimestampProvided(
-                    start_time_i64
-                )));
-            }
+    /** Same as [`Period::from_timestamp`], but in `time_zone`.
+     **/
+    pub fn from_timestamp_in(
+        start_time_i64: i64,
+        end_time_i64: i64,
+        time_zone: Tz,
+    ) -> Result<Self> {
+        let from_timestamp = |timestamp: i64| match time_zone.timestamp_opt(timestamp, 0).single() {
+            Some(time) => Ok(time),
+            None => Err(Error::from(ParseError::InvalidTimestampProvided(
+                timestamp.to_string(),
+            ))),
         };
 
-        let end_time: DateTime<Tz> = match parse(&end_time_i64) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
-            Err(_) => {
-                return Err(anyhow!(ParseError::InvalidTimestampProvided(end_time_i64)));
-            }
-        };
+        let start_time: DateTime<Tz> = from_timestamp(start_time_i64)?;
+        let end_time: DateTime<Tz> = from_timestamp(end_time_i64)?;
 
-        Ok(Self {
-            start_time,
-            end_time,
-        })
+        Self::new(start_time, end_time)
     }
 
     /** Create a new Period instance of current day borders
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::this_day();
 
@@ -119,22 +275,22 @@ impl Period {
     ```
     **/
     pub fn this_day() -> Self {
-        let today_date: DateTime<Tz> = Utc::now().with_timezone(&Europe__Kiev);
+        Self::this_day_in(DEFAULT_TIME_ZONE)
+    }
 
-        let start_time: DateTime<Tz> = today_date.beginning_of_day();
-        let end_time: DateTime<Tz> = today_date.end_of_day();
+    /** Same as [`Period::this_day`], but in `time_zone`.
+     **/
+    pub fn this_day_in(time_zone: Tz) -> Self {
+        let today_date: NaiveDate = today_in(time_zone);
 
-        Self {
-            start_time,
-            end_time,
-        }
+        DateRange::new(today_date, today_date).period_in(time_zone)
     }
 
     /** Create a new Period instance of day from current_time.
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
 
     let period: Period = Period::now();
@@ -148,12 +304,18 @@ impl Period {
 
     */
     pub fn now() -> Self {
+        Self::now_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::now`], but in `time_zone`.
+     **/
+    pub fn now_in(time_zone: Tz) -> Self {
         let start_time: DateTime<Tz> = Utc::now()
             .checked_sub_signed(Duration::minutes(90))
             .unwrap()
-            .with_timezone(&Europe__Kiev);
+            .with_timezone(&time_zone);
 
-        let end_time: DateTime<Tz> = start_time.end_of_day();
+        let end_time: DateTime<Tz> = end_of(start_time.date_naive(), time_zone);
 
         Self {
             start_time,
@@ -165,7 +327,7 @@ impl Period {
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::next_day();
 
@@ -174,24 +336,21 @@ impl Period {
     ```
     **/
     pub fn next_day() -> Self {
-        let today_date: DateTime<Tz> = Utc::now()
-            .with_timezone(&Europe__Kiev)
-            .checked_add_signed(Duration::days(1))
-            .unwrap();
+        Self::next_day_in(DEFAULT_TIME_ZONE)
+    }
 
-        let start_time: DateTime<Tz> = today_date.beginning_of_day();
-        let end_time: DateTime<Tz> = today_date.end_of_day();
+    /** Same as [`Period::next_day`], but in `time_zone`.
+     **/
+    pub fn next_day_in(time_zone: Tz) -> Self {
+        let next_date: NaiveDate = today_in(time_zone) + Duration::days(1);
 
-        Self {
-            start_time,
-            end_time,
-        }
+        DateRange::new(next_date, next_date).period_in(time_zone)
     }
 
     /** Create a new Period instance of 1 day from start_time
     # Examples:
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::day_from("2023-01-02")?;
 
@@ -201,31 +360,37 @@ impl Period {
     # Errors
     This function fails if:
      [`ParseError::InvalidStringProvided`] - Can't parse datetime from given string.
+     [`ParseError::YearOutOfRange`] - Date is not between [`MIN_YEAR`] and [`MAX_YEAR`].
      */
     pub fn day_from(start_time_str: &str) -> Result<Self> {
-        let parsed_date: DateTime<Tz> = match parse(start_time_str) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
+        Self::day_from_in(start_time_str, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::day_from`], but in `time_zone`.
+
+    Strings without an UTC offset are read as local time of `time_zone`.
+    **/
+    pub fn day_from_in(start_time_str: &str, time_zone: Tz) -> Result<Self> {
+        let parsed_date: DateTime<Tz> = match parse_with_timezone(start_time_str, &time_zone) {
+            Ok(parsed) => parsed.with_timezone(&time_zone),
             Err(_) => {
-                return Err(anyhow!(ParseError::InvalidStringProvided(String::from(
-                    start_time_str
-                ))));
+                return Err(Error::from(ParseError::InvalidStringProvided(
+                    String::from(start_time_str),
+                )));
             }
         };
 
-        let start_time: DateTime<Tz> = parsed_date.beginning_of_day();
-        let end_time: DateTime<Tz> = parsed_date.end_of_day();
+        let date: NaiveDate = parsed_date.date_naive();
+        let period = DateRange::new(date, date).period_in(time_zone);
 
-        Ok(Self {
-            start_time,
-            end_time,
-        })
+        Self::new(period.start_time, period.end_time)
     }
 
     /** Create a new Period instance of current week borders
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::this_week();
 
@@ -234,21 +399,20 @@ impl Period {
     ```
     **/
     pub fn this_week() -> Self {
-        let today_date: DateTime<Tz> = Utc::now().with_timezone(&Europe__Kiev);
+        Self::this_week_in(DEFAULT_TIME_ZONE)
+    }
 
-        let start_time: DateTime<Tz> = today_date.beginning_of_week();
-        let end_time: DateTime<Tz> = today_date.end_of_week();
-        Self {
-            start_time,
-            end_time,
-        }
+    /** Same as [`Period::this_week`], but in `time_zone`.
+     **/
+    pub fn this_week_in(time_zone: Tz) -> Self {
+        DateRange::week_of(today_in(time_zone)).period_in(time_zone)
     }
 
     /** Create a new Period instance of next week borders
 
     # Examples
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::next_week();
 
@@ -257,24 +421,19 @@ impl Period {
     ```
     **/
     pub fn next_week() -> Self {
-        let today_date: DateTime<Tz> = Utc::now()
-            .with_timezone(&Europe__Kiev)
-            .checked_add_signed(Duration::weeks(1))
-            .unwrap();
-
-        let start_time: DateTime<Tz> = today_date.beginning_of_week();
-        let end_time: DateTime<Tz> = today_date.end_of_week();
+        Self::next_week_in(DEFAULT_TIME_ZONE)
+    }
 
-        Self {
-            start_time,
-            end_time,
-        }
+    /** Same as [`Period::next_week`], but in `time_zone`.
+     **/
+    pub fn next_week_in(time_zone: Tz) -> Self {
+        DateRange::week_of(today_in(time_zone) + Duration::weeks(1)).period_in(time_zone)
     }
 
     /** Create a new Period instance of 1 week from start_time
     # Examples:
     ```
-    # use anyhow::Error;
+    # use nure_tools::Error;
     # use nure_tools::utils::Period;
     let period: Period = Period::week_from("2023-01-02")?;
 
@@ -284,25 +443,530 @@ impl Period {
     # Errors
     This function fails if:
         [`ParseError::InvalidStringProvided`] - Can't parse datetime from given string.
+        [`ParseError::YearOutOfRange`] - Date is not between [`MIN_YEAR`] and [`MAX_YEAR`].
      */
     pub fn week_from(start_time_str: &str) -> Result<Self> {
-        let parsed_date: DateTime<Tz> = match parse(start_time_str) {
-            Ok(parsed) => parsed.with_timezone(&Europe__Kiev),
+        Self::week_from_in(start_time_str, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::week_from`], but in `time_zone`.
+
+    Strings without an UTC offset are read as local time of `time_zone`.
+    **/
+    pub fn week_from_in(start_time_str: &str, time_zone: Tz) -> Result<Self> {
+        let parsed_date: DateTime<Tz> = match parse_with_timezone(start_time_str, &time_zone) {
+            Ok(parsed) => parsed.with_timezone(&time_zone),
             Err(_) => {
-                return Err(anyhow!(ParseError::InvalidStringProvided(String::from(
-                    start_time_str
-                ))));
+                return Err(Error::from(ParseError::InvalidStringProvided(
+                    String::from(start_time_str),
+                )));
             }
         };
 
-        let start_time: DateTime<Tz> = parsed_date.beginning_of_week();
-        let end_time = parsed_date.end_of_week();
+        let period = DateRange::week_of(parsed_date.date_naive()).period_in(time_zone);
 
-        Ok(Self {
-            start_time,
-            end_time,
+        Self::new(period.start_time, period.end_time)
+    }
+
+    /** Create a new Period instance of current month borders
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::this_month();
+
+    println!("Period: {:#?}", period);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn this_month() -> Self {
+        Self::this_month_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::this_month`], but in `time_zone`.
+     **/
+    pub fn this_month_in(time_zone: Tz) -> Self {
+        DateRange::month_of(today_in(time_zone)).period_in(time_zone)
+    }
+
+    /** Create a new Period instance of `days` days starting today
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::next_days(3);
+
+    assert_eq!((period.end_time.date_naive() - period.start_time.date_naive()).num_days(), 2);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn next_days(days: u32) -> Self {
+        Self::next_days_in(days, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::next_days`], but in `time_zone`.
+     **/
+    pub fn next_days_in(days: u32, time_zone: Tz) -> Self {
+        let today_date: NaiveDate = today_in(time_zone);
+        let last_date: NaiveDate = today_date + Duration::days(days.saturating_sub(1) as i64);
+
+        DateRange::new(today_date, last_date).period_in(time_zone)
+    }
+
+    /** Create a new Period instance of `weeks` weeks starting this week
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::next_weeks(2);
+
+    println!("Period: {:#?}", period);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn next_weeks(weeks: u32) -> Self {
+        Self::next_weeks_in(weeks, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::next_weeks`], but in `time_zone`.
+     **/
+    pub fn next_weeks_in(weeks: u32, time_zone: Tz) -> Self {
+        let today_date: NaiveDate = today_in(time_zone);
+        let last_date: NaiveDate = today_date + Duration::weeks(weeks.saturating_sub(1) as i64);
+
+        DateRange::new(
+            DateRange::week_of(today_date).start,
+            DateRange::week_of(last_date).end,
+        )
+        .period_in(time_zone)
+    }
+
+    /** Create a new Period instance of current semester borders, exam session included
+
+    During holidays this is the upcoming semester. Dates come from [`AcademicCalendar::nure`],
+    see [`Period::this_semester_of`] for other calendars.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::this_semester();
+
+    println!("Period: {:#?}", period);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn this_semester() -> Self {
+        Self::this_semester_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::this_semester`], but in `time_zone`, for the current day there.
+     **/
+    pub fn this_semester_in(time_zone: Tz) -> Self {
+        Self::this_semester_of(&AcademicCalendar::nure_current_in(time_zone), time_zone)
+            .unwrap_or_else(|| {
+                let (calendar, semester) = upcoming_semester(today_in(time_zone))
+                    .expect("NURE calendar of the next year is valid");
+                calendar.semester_in(semester, time_zone)
+            })
+    }
+
+    /** Create a new Period instance of the semester of `calendar` going on today, in `time_zone`
+
+    During holidays this is the upcoming semester, `None` after the end of the spring semester of `calendar`.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::{calendar::AcademicCalendar, utils::{Period, DEFAULT_TIME_ZONE}};
+    let calendar = AcademicCalendar::nure_current();
+
+    if let Some(period) = Period::this_semester_of(&calendar, DEFAULT_TIME_ZONE) {
+        assert!(calendar.academic_year().contains_period(&period));
+    }
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn this_semester_of(calendar: &AcademicCalendar, time_zone: Tz) -> Option<Self> {
+        let semester = calendar.semester_around(today_in(time_zone))?;
+        Some(calendar.semester_in(semester, time_zone))
+    }
+
+    /** Create a new Period instance of the semester after [`Period::this_semester`]
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::next_semester();
+
+    assert!(period.start_time > Period::this_semester().end_time);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn next_semester() -> Self {
+        Self::next_semester_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::next_semester`], but in `time_zone`, for the current day there.
+     **/
+    pub fn next_semester_in(time_zone: Tz) -> Self {
+        let (calendar, semester) =
+            upcoming_semester(Self::this_semester_in(time_zone).end_time.date_naive())
+                .expect("NURE calendar of the next year is valid");
+        calendar.semester_in(semester, time_zone)
+    }
+
+    /** Create a new Period instance of the semester of `calendar` after [`Period::this_semester_of`], in `time_zone`
+
+    `None` if there is no such semester in `calendar`, i.e. from the spring semester on.
+    **/
+    pub fn next_semester_of(calendar: &AcademicCalendar, time_zone: Tz) -> Option<Self> {
+        match calendar.semester_around(today_in(time_zone))? {
+            Semester::Autumn => Some(calendar.semester_in(Semester::Spring, time_zone)),
+            Semester::Spring => None,
+        }
+    }
+
+    /** Create a new Period instance of current academic year borders
+
+    From the first day of the autumn semester to the end of the summer exam session,
+    see [`AcademicCalendar::academic_year`].
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::this_academic_year();
+
+    println!("Period: {:#?}", period);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn this_academic_year() -> Self {
+        Self::this_academic_year_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::this_academic_year`], but in `time_zone`, for the current day there.
+     **/
+    pub fn this_academic_year_in(time_zone: Tz) -> Self {
+        Self::this_academic_year_of(&AcademicCalendar::nure_current_in(time_zone), time_zone)
+    }
+
+    /** Create a new Period instance of the academic year of `calendar`, in `time_zone`
+     **/
+    pub fn this_academic_year_of(calendar: &AcademicCalendar, time_zone: Tz) -> Self {
+        calendar.academic_year_in(time_zone)
+    }
+
+    /** Create a new Period instance of current exam session borders, or of the next one outside of sessions
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::exam_session();
+
+    println!("Period: {:#?}", period);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn exam_session() -> Self {
+        Self::exam_session_in(DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::exam_session`], but in `time_zone`, for the current day there.
+     **/
+    pub fn exam_session_in(time_zone: Tz) -> Self {
+        let (calendar, semester) =
+            upcoming_session(today_in(time_zone)).expect("NURE calendar of the next year is valid");
+        calendar.session_in(semester, time_zone)
+    }
+
+    /** Create a new Period instance of the exam session of `calendar` going on today or coming next, in `time_zone`
+
+    `None` after the end of the spring session of `calendar`.
+    **/
+    pub fn exam_session_of(calendar: &AcademicCalendar, time_zone: Tz) -> Option<Self> {
+        let semester = calendar.session_around(today_in(time_zone))?;
+        Some(calendar.session_in(semester, time_zone))
+    }
+
+    /** Create a new Period instance from a Ukrainian or English description, e.g. "завтра" or "з 3 по 7 березня"
+
+    See [`parse_period`] for everything that is understood.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period: Period = Period::from_natural("на наступному тижні")?;
+
+    assert_eq!(period, Period::next_week());
+    # Ok::<(), Error>(())
+    ```
+    # Errors
+    This function fails if:
+        [`parse_period`] fails.
+
+    [`parse_period`]: `crate::natural::parse_period`
+    **/
+    pub fn from_natural(input: &str) -> Result<Self> {
+        Self::from_natural_in(input, DEFAULT_TIME_ZONE)
+    }
+
+    /** Same as [`Period::from_natural`], but in `time_zone`, relative to the current day there.
+     **/
+    pub fn from_natural_in(input: &str, time_zone: Tz) -> Result<Self> {
+        parse_period(input, today_in(time_zone), time_zone)
+    }
+
+    /** Same Period with both ends in `time_zone`.
+     **/
+    pub fn with_time_zone(&self, time_zone: Tz) -> Period {
+        Self {
+            start_time: self.start_time.with_timezone(&time_zone),
+            end_time: self.end_time.with_timezone(&time_zone),
+        }
+    }
+
+    /** Length of the Period, zero if it ends before it starts.
+     **/
+    pub fn duration(&self) -> Duration {
+        max(self.end_time - self.start_time, Duration::zero())
+    }
+
+    /** Say if the Period has no length.
+     **/
+    pub fn is_empty(&self) -> bool {
+        self.end_time <= self.start_time
+    }
+
+    /** Say if `time` is within the Period, the end excluded.
+     **/
+    pub fn contains(&self, time: &DateTime<Tz>) -> bool {
+        self.start_time <= *time && *time < self.end_time
+    }
+
+    /** Say if `other` lies entirely within the Period.
+     **/
+    pub fn contains_period(&self, other: &Period) -> bool {
+        self.start_time <= other.start_time && other.end_time <= self.end_time
+    }
+
+    /** Say if the Period and `other` share any time.
+
+    Periods that only touch, i.e. one ends exactly when the other starts, don't overlap.
+    **/
+    pub fn overlaps(&self, other: &Period) -> bool {
+        self.start_time < other.end_time && other.start_time < self.end_time
+    }
+
+    /** Time shared by the Period and `other`, `None` if they don't overlap.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let morning = Period::from_string("2024-01-02T08:00:00+02:00", "2024-01-02T12:00:00+02:00")?;
+    let lunch = Period::from_string("2024-01-02T11:00:00+02:00", "2024-01-02T13:00:00+02:00")?;
+
+    let shared: Period = morning.intersection(&lunch).unwrap();
+
+    assert_eq!(shared.start_time, lunch.start_time);
+    assert_eq!(shared.end_time, morning.end_time);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn intersection(&self, other: &Period) -> Option<Period> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        Some(Self {
+            start_time: max(self.start_time, other.start_time),
+            end_time: min(self.end_time, other.end_time),
+        })
+    }
+
+    /** Period covering both the Period and `other`, `None` if they neither overlap nor touch.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let first = Period::from_string("2024-01-02T08:00:00+02:00", "2024-01-02T10:00:00+02:00")?;
+    let second = Period::from_string("2024-01-02T10:00:00+02:00", "2024-01-02T12:00:00+02:00")?;
+    let evening = Period::from_string("2024-01-02T18:00:00+02:00", "2024-01-02T20:00:00+02:00")?;
+
+    let merged: Period = first.union(&second).unwrap();
+
+    assert_eq!(merged.start_time, first.start_time);
+    assert_eq!(merged.end_time, second.end_time);
+    assert!(merged.union(&evening).is_none());
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn union(&self, other: &Period) -> Option<Period> {
+        if self.start_time > other.end_time || other.start_time > self.end_time {
+            return None;
+        }
+
+        Some(Self {
+            start_time: min(self.start_time, other.start_time),
+            end_time: max(self.end_time, other.end_time),
         })
     }
+
+    /** Move the Period by `offset`, which can be negative.
+
+    `None` if any of the moved times can't be represented.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    use chrono::Duration;
+
+    let period = Period::from_string("2024-01-02T08:00:00+02:00", "2024-01-02T10:00:00+02:00")?;
+
+    let later: Period = period.shift(Duration::days(7)).unwrap();
+    assert_eq!(later.start_time.to_rfc3339(), "2024-01-09T08:00:00+02:00");
+    assert!(period.shift(Duration::days(1_000_000_000)).is_none());
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn shift(&self, offset: Duration) -> Option<Period> {
+        Some(Self {
+            start_time: self.start_time.checked_add_signed(offset)?,
+            end_time: self.end_time.checked_add_signed(offset)?,
+        })
+    }
+
+    /** Split the Period at midnights of its time zone.
+
+    # Examples
+    ```
+    # use nure_tools::Error;
+    # use nure_tools::utils::Period;
+    let period = Period::from_string("2024-01-02T20:00:00+02:00", "2024-01-04T10:00:00+02:00")?;
+
+    let days: Vec<Period> = period.split_by_day().collect();
+
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[0].start_time, period.start_time);
+    assert_eq!(days[0].end_time, days[1].start_time);
+    assert_eq!(days[2].end_time, period.end_time);
+    # Ok::<(), Error>(())
+    ```
+    **/
+    pub fn split_by_day(&self) -> impl Iterator<Item = Period> {
+        self.split_by(|time| end_of(time.date_naive(), time.timezone()))
+    }
+
+    /** Split the Period at the beginnings of weeks (Mondays) of its time zone.
+     **/
+    pub fn split_by_week(&self) -> impl Iterator<Item = Period> {
+        self.split_by(|time| end_of(DateRange::week_of(time.date_naive()).end, time.timezone()))
+    }
+
+    fn split_by(
+        &self,
+        last_moment: impl Fn(&DateTime<Tz>) -> DateTime<Tz>,
+    ) -> impl Iterator<Item = Period> {
+        let end_time = self.end_time;
+        let mut cursor = self.start_time;
+
+        std::iter::from_fn(move || {
+            if cursor >= end_time {
+                return None;
+            }
+
+            let boundary = last_moment(&cursor) + Duration::nanoseconds(1);
+            let piece = Period {
+                start_time: cursor,
+                end_time: min(boundary, end_time),
+            };
+            cursor = boundary;
+
+            Some(piece)
+        })
+    }
+}
+
+/** Period from the beginning of the first date to the end of the second one, in Kyiv time.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::{errors::ParseError, utils::Period};
+use chrono::NaiveDate;
+
+let start = NaiveDate::from_ymd_opt(2024, 3, 3).unwrap();
+let end = NaiveDate::from_ymd_opt(2024, 3, 7).unwrap();
+
+let period = Period::try_from((start, end))?;
+
+assert_eq!(period.start_time.date_naive(), start);
+assert_eq!(period.end_time.date_naive(), end);
+assert!(matches!(
+    Period::try_from((end, start)),
+    Err(Error::Parse(ParseError::InvertedPeriod(_)))
+));
+# Ok::<(), Error>(())
+```
+# Errors
+This conversion fails if:
+ * [`Period::validate`] fails, e.g. for dates given in reverse order.
+**/
+impl TryFrom<(NaiveDate, NaiveDate)> for Period {
+    type Error = Error;
+
+    fn try_from((start, end): (NaiveDate, NaiveDate)) -> Result<Self> {
+        let period = DateRange::new(start, end).period();
+        Self::new(period.start_time, period.end_time)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Period {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Period", 3)?;
+        state.serialize_field("start_time", &self.start_time.to_rfc3339())?;
+        state.serialize_field("end_time", &self.end_time.to_rfc3339())?;
+        state.serialize_field("time_zone", self.start_time.timezone().name())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Period {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct PeriodRepr {
+            start_time: String,
+            end_time: String,
+            time_zone: String,
+        }
+
+        let repr = PeriodRepr::deserialize(deserializer)?;
+        let time_zone: Tz = repr.time_zone.parse().map_err(D::Error::custom)?;
+        let start_time = DateTime::parse_from_rfc3339(&repr.start_time)
+            .map_err(D::Error::custom)?
+            .with_timezone(&time_zone);
+        let end_time = DateTime::parse_from_rfc3339(&repr.end_time)
+            .map_err(D::Error::custom)?
+            .with_timezone(&time_zone);
+
+        Self::new(start_time, end_time).map_err(D::Error::custom)
+    }
 }
 
 impl fmt::Display for Period {
@@ -315,6 +979,249 @@ impl fmt::Display for Period {
     }
 }
 
+/** Set of periods, kept sorted and with overlapping or touching periods merged.
+
+Empty periods are ignored.
+
+# Examples
+```
+# use nure_tools::Error;
+# use nure_tools::utils::{Period, PeriodSet};
+use chrono::Duration;
+
+let busy: PeriodSet = [
+    Period::from_string("2024-01-02T11:15:00+02:00", "2024-01-02T12:50:00+02:00")?,
+    Period::from_string("2024-01-02T07:45:00+02:00", "2024-01-02T09:20:00+02:00")?,
+    Period::from_string("2024-01-02T09:00:00+02:00", "2024-01-02T11:05:00+02:00")?,
+]
+.into_iter()
+.collect();
+
+assert_eq!(busy.len(), 2);
+assert_eq!(busy.total_duration(), Duration::minutes(200 + 95));
+
+let day = Period::from_string("2024-01-02T07:00:00+02:00", "2024-01-02T13:00:00+02:00")?;
+let free: PeriodSet = busy.gaps(&day);
+
+assert_eq!(free.len(), 3);
+assert_eq!(free.periods()[1].duration(), Duration::minutes(10));
+# Ok::<(), Error>(())
+```
+
+# Serialization
+With the `serde` feature a PeriodSet is (de)serialized as a struct with its `periods`,
+deserialized periods are sorted and merged like any other.
+```
+# use nure_tools::Error;
+# use nure_tools::utils::PeriodSet;
+# #[cfg(feature = "serde")]
+# {
+let json = r#"{"periods": [
+    {"start_time": "2024-01-02T11:15:00+02:00", "end_time": "2024-01-02T12:50:00+02:00", "time_zone": "Europe/Kiev"},
+    {"start_time": "2024-01-02T07:45:00+02:00", "end_time": "2024-01-02T12:00:00+02:00", "time_zone": "Europe/Kiev"}
+]}"#;
+let set: PeriodSet = serde_json::from_str(json)?;
+
+assert_eq!(set.len(), 1);
+assert_eq!(set.periods()[0].start_time.to_rfc3339(), "2024-01-02T07:45:00+02:00");
+# }
+# Ok::<(), Error>(())
+```
+**/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PeriodSet {
+    periods: Vec<Period>,
+}
+
+impl PeriodSet {
+    /** Create a new empty PeriodSet.
+     **/
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /** Add `period` to the set, merging it with the periods it overlaps or touches.
+     **/
+    pub fn insert(&mut self, period: Period) {
+        if period.is_empty() {
+            return;
+        }
+
+        self.periods.push(period);
+        self.normalize();
+    }
+
+    /** Periods of the set in order.
+     **/
+    pub fn periods(&self) -> &[Period] {
+        &self.periods
+    }
+
+    /** Iterator over the periods of the set in order.
+     **/
+    pub fn iter(&self) -> std::slice::Iter<'_, Period> {
+        self.periods.iter()
+    }
+
+    /** Number of disjoint periods in the set.
+     **/
+    pub fn len(&self) -> usize {
+        self.periods.len()
+    }
+
+    /** Say if the set has no periods.
+     **/
+    pub fn is_empty(&self) -> bool {
+        self.periods.is_empty()
+    }
+
+    /** Say if `time` is within any period of the set.
+     **/
+    pub fn contains(&self, time: &DateTime<Tz>) -> bool {
+        self.periods.iter().any(|period| period.contains(time))
+    }
+
+    /** Say if any period of the set overlaps `period`.
+     **/
+    pub fn overlaps(&self, period: &Period) -> bool {
+        self.periods
+            .iter()
+            .any(|existing| existing.overlaps(period))
+    }
+
+    /** Sum of the durations of every period of the set.
+     **/
+    pub fn total_duration(&self) -> Duration {
+        self.periods
+            .iter()
+            .fold(Duration::zero(), |total, period| total + period.duration())
+    }
+
+    /** Time covered by either set.
+     **/
+    pub fn union(&self, other: &PeriodSet) -> PeriodSet {
+        self.iter().chain(other.iter()).cloned().collect()
+    }
+
+    /** Time covered by both sets.
+     **/
+    pub fn intersection(&self, other: &PeriodSet) -> PeriodSet {
+        self.iter()
+            .flat_map(|period| other.iter().filter_map(|other| period.intersection(other)))
+            .collect()
+    }
+
+    /** Time covered by the set but not by `other`.
+     **/
+    pub fn difference(&self, other: &PeriodSet) -> PeriodSet {
+        let mut remaining: Vec<Period> = self.periods.clone();
+
+        for cut in other.iter() {
+            remaining = remaining
+                .into_iter()
+                .flat_map(|period| {
+                    let mut pieces: Vec<Period> = Vec::new();
+                    if !period.overlaps(cut) {
+                        pieces.push(period);
+                        return pieces;
+                    }
+                    if period.start_time < cut.start_time {
+                        pieces.push(Period {
+                            start_time: period.start_time,
+                            end_time: cut.start_time,
+                        });
+                    }
+                    if cut.end_time < period.end_time {
+                        pieces.push(Period {
+                            start_time: cut.end_time,
+                            end_time: period.end_time,
+                        });
+                    }
+                    pieces
+                })
+                .collect();
+        }
+
+        Self { periods: remaining }
+    }
+
+    /** Parts of `within` not covered by the set.
+     **/
+    pub fn gaps(&self, within: &Period) -> PeriodSet {
+        PeriodSet::from_iter([within.clone()]).difference(self)
+    }
+
+    fn normalize(&mut self) {
+        self.periods.sort_by_key(|period| period.start_time);
+
+        let mut merged: Vec<Period> = Vec::with_capacity(self.periods.len());
+        for period in self.periods.drain(..) {
+            if let Some(last) = merged.last_mut() {
+                if let Some(union) = last.union(&period) {
+                    *last = union;
+                    continue;
+                }
+            }
+            merged.push(period);
+        }
+        self.periods = merged;
+    }
+}
+
+/// Deserialized periods are normalized, as if they were collected into the set.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PeriodSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct PeriodSetRepr {
+            periods: Vec<Period>,
+        }
+
+        let repr = PeriodSetRepr::deserialize(deserializer)?;
+        Ok(repr.periods.into_iter().collect())
+    }
+}
+
+impl FromIterator<Period> for PeriodSet {
+    fn from_iter<I: IntoIterator<Item = Period>>(iter: I) -> Self {
+        let mut set = Self {
+            periods: iter
+                .into_iter()
+                .filter(|period| !period.is_empty())
+                .collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+impl Extend<Period> for PeriodSet {
+    fn extend<I: IntoIterator<Item = Period>>(&mut self, iter: I) {
+        self.periods
+            .extend(iter.into_iter().filter(|period| !period.is_empty()));
+        self.normalize();
+    }
+}
+
+impl IntoIterator for PeriodSet {
+    type Item = Period;
+    type IntoIter = std::vec::IntoIter<Period>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.periods.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PeriodSet {
+    type Item = &'a Period;
+    type IntoIter = std::slice::Iter<'a, Period>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.periods.iter()
+    }
+}
+
 /** Function to say if `find_it` presented in `search_here` using RegExp.
 # Arguments
  * `find_it` - the string to be found.
@@ -322,7 +1229,7 @@ impl fmt::Display for Period {
 
 # Examples
 ```
-# use anyhow::Error;
+# use nure_tools::Error;
 # use nure_tools::utils::find;
 let find_it: &str = "пі";
 let search_here: &str = "пзпі-23-2";
@@ -346,8 +1253,8 @@ pub fn find(find_it: &str, search_here: &str) -> Result<bool> {
     let regex: Regex = match Regex::new(find_it.to_lowercase().as_str()) {
         Ok(compiled) => compiled,
         Err(_) => {
-            return Err(anyhow!(FindError::InvalidRegexString(String::from(
-                find_it
+            return Err(Error::from(FindError::InvalidRegexString(String::from(
+                find_it,
             ))));
         }
     };
@@ -357,26 +1264,47 @@ pub fn find(find_it: &str, search_here: &str) -> Result<bool> {
 
 /** Helper function to catch errors while waiting for Get result.
 
-You probably will never use it, but you can if you want, see example in [get_groups]/[get_teachers]/[get_lecture_rooms]/[get_schedule] functions sources.
+You probably will never use it, the crate itself goes through [`Transport`] and [`TransportResponse::into_json`] instead.
 
-[get_groups]: `crate::groups::get_groups`
-[get_teachers]: `crate::teachers::get_teachers`
-[get_lecture_rooms]: `crate::lecture_rooms::get_lecture_rooms`
-[get_schedule]: `crate::schedule::get_schedule`
+[`Transport`]: `crate::transport::Transport`
+[`TransportResponse::into_json`]: `crate::transport::TransportResponse::into_json`
 **/
+#[cfg(feature = "blocking")]
 pub fn get_wrapper(get_response: reqwest::Result<Response>) -> Result<Value> {
     match get_response {
         Ok(value) => match value.status().as_u16() {
-            200 => match value.json::<serde_json::Value>() {
-                Ok(value) => Ok(value),
-                Err(_) => Err(anyhow!(RequestError::NotJson)),
+            200 => match value.text() {
+                Ok(body) => Ok(serde_json::from_str::<Value>(&body)?),
+                Err(error) => Err(send_error(error)),
             },
-            _ => Err(anyhow!(RequestError::BadResponse(
+            _ => Err(Error::from(RequestError::BadResponse(
                 String::from(value.status().canonical_reason().unwrap_or("")),
-                value.status().as_u16()
+                value.status().as_u16(),
             ))),
         },
 
-        Err(_) => Err(anyhow!(RequestError::GetFailed)),
+        Err(error) => Err(send_error(error)),
+    }
+}
+
+/** Helper function to show `span` in days, hours, minutes and seconds, e.g. "1d 2h 30m", skipping zero parts.
+**/
+fn format_span(span: Duration) -> String {
+    let seconds = span.num_seconds();
+    let parts: Vec<String> = [
+        (seconds / 86_400, "d"),
+        (seconds % 86_400 / 3_600, "h"),
+        (seconds % 3_600 / 60, "m"),
+        (seconds % 60, "s"),
+    ]
+    .into_iter()
+    .filter(|(value, _)| *value != 0)
+    .map(|(value, unit)| format!("{}{}", value, unit))
+    .collect();
+
+    if parts.is_empty() {
+        String::from("0s")
+    } else {
+        parts.join(" ")
     }
 }
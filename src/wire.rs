@@ -0,0 +1,273 @@
+use crate::{
+    errors::{Error, ParseError, RecordError, Result},
+    groups::Group,
+    lecture_rooms::LectureRoom,
+    schedule::{Lecture, LectureType, Subject},
+    teachers::Teacher,
+    utils::Period,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+/** Record of an API response that can be turned into a data type of this crate.
+**/
+pub(crate) trait Record: DeserializeOwned {
+    type Output;
+
+    /** Turn the deserialized record into its data type.
+
+    Returns the field and the json found there if the record is well-formed but still invalid.
+    **/
+    fn convert(self) -> std::result::Result<Self::Output, (String, String)>;
+}
+
+/** Group as returned by the API.
+**/
+#[derive(Debug, Deserialize)]
+pub(crate) struct GroupRecord {
+    id: i32,
+    name: String,
+}
+
+impl From<GroupRecord> for Group {
+    fn from(record: GroupRecord) -> Self {
+        Group::new(record.id, record.name)
+    }
+}
+
+impl Record for GroupRecord {
+    type Output = Group;
+
+    fn convert(self) -> std::result::Result<Group, (String, String)> {
+        Ok(self.into())
+    }
+}
+
+/** Teacher as returned by the API.
+**/
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TeacherRecord {
+    id: i32,
+    short_name: String,
+    full_name: String,
+}
+
+impl From<TeacherRecord> for Teacher {
+    fn from(record: TeacherRecord) -> Self {
+        Teacher::new(record.id, record.short_name, record.full_name)
+    }
+}
+
+impl Record for TeacherRecord {
+    type Output = Teacher;
+
+    fn convert(self) -> std::result::Result<Teacher, (String, String)> {
+        Ok(self.into())
+    }
+}
+
+/** Lecture room as returned by the API.
+**/
+#[derive(Debug, Deserialize)]
+pub(crate) struct LectureRoomRecord {
+    id: i32,
+    name: String,
+}
+
+impl From<LectureRoomRecord> for LectureRoom {
+    fn from(record: LectureRoomRecord) -> Self {
+        LectureRoom::new(record.id, record.name)
+    }
+}
+
+impl Record for LectureRoomRecord {
+    type Output = LectureRoom;
+
+    fn convert(self) -> std::result::Result<LectureRoom, (String, String)> {
+        Ok(self.into())
+    }
+}
+
+/** Subject as returned by the API.
+**/
+#[derive(Debug, Deserialize)]
+pub(crate) struct SubjectRecord {
+    id: i32,
+    title: String,
+    brief: String,
+}
+
+impl From<SubjectRecord> for Subject {
+    fn from(record: SubjectRecord) -> Self {
+        Subject::new(record.brief, record.id, record.title)
+    }
+}
+
+impl Record for SubjectRecord {
+    type Output = Subject;
+
+    fn convert(self) -> std::result::Result<Subject, (String, String)> {
+        Ok(self.into())
+    }
+}
+
+/** Lecture as returned by the schedule endpoints of the API.
+**/
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LectureRecord {
+    auditory: String,
+    start_time: i64,
+    end_time: i64,
+    number_pair: u8,
+    #[serde(rename = "type")]
+    lecture_type: String,
+    teachers: Vec<TeacherRecord>,
+    groups: Vec<GroupRecord>,
+    subject: SubjectRecord,
+}
+
+impl Record for LectureRecord {
+    type Output = Lecture;
+
+    fn convert(self) -> std::result::Result<Lecture, (String, String)> {
+        let period = match Period::from_timestamp(self.start_time, self.end_time) {
+            Ok(period) => period,
+            Err(_) => {
+                return Err((
+                    String::from("startTime"),
+                    format!("{}..{}", self.start_time, self.end_time),
+                ))
+            }
+        };
+
+        Ok(Lecture::new(
+            self.auditory,
+            period,
+            self.number_pair,
+            LectureType::from(self.lecture_type),
+            self.teachers.into_iter().map(Teacher::from).collect(),
+            self.groups.into_iter().map(Group::from).collect(),
+            self.subject.into(),
+        ))
+    }
+}
+
+/** Records successfully turned into data types, and the ones that were skipped.
+**/
+#[derive(Debug)]
+pub(crate) struct Decoded<T> {
+    pub(crate) items: Vec<T>,
+    pub(crate) warnings: Vec<RecordError>,
+}
+
+/** Helper function to turn every record of an API response into its data type.
+
+In lenient mode invalid records are skipped and returned as warnings,
+otherwise the first invalid record fails the whole response.
+
+# Errors
+This function fails if:
+ * [`ParseError::InvalidRecord`] - Any of the records is invalid and `lenient` is false.
+**/
+pub(crate) fn decode<R: Record>(records: Vec<Value>, lenient: bool) -> Result<Decoded<R::Output>> {
+    if lenient {
+        return Ok(decode_lenient::<R>(records));
+    }
+
+    let mut items: Vec<R::Output> = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        match decode_record::<R>(index, record) {
+            Ok(item) => items.push(item),
+            Err(error) => return Err(Error::from(ParseError::InvalidRecord(error))),
+        }
+    }
+
+    Ok(Decoded {
+        items,
+        warnings: Vec::new(),
+    })
+}
+
+/** Helper function to turn every valid record of an API response into its data type, skipping the invalid ones.
+**/
+pub(crate) fn decode_lenient<R: Record>(records: Vec<Value>) -> Decoded<R::Output> {
+    let mut decoded = Decoded {
+        items: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    for (index, record) in records.iter().enumerate() {
+        match decode_record::<R>(index, record) {
+            Ok(item) => decoded.items.push(item),
+            Err(error) => decoded.warnings.push(error),
+        }
+    }
+
+    decoded
+}
+
+/** Helper function to turn a single record of an API response into its data type.
+**/
+pub(crate) fn decode_record<R: Record>(
+    index: usize,
+    record: &Value,
+) -> std::result::Result<R::Output, RecordError> {
+    let invalid = |field: String, found: String| RecordError {
+        record: index,
+        field,
+        found,
+    };
+
+    let error = match serde_path_to_error::deserialize::<_, R>(record) {
+        Ok(parsed) => {
+            return parsed
+                .convert()
+                .map_err(|(field, found)| invalid(field, found))
+        }
+        Err(error) => error,
+    };
+
+    let mut field = String::new();
+    let mut value = Some(record);
+    for segment in error.path() {
+        match segment {
+            Segment::Seq { index } => {
+                field.push_str(&format!("[{}]", index));
+                value = value.and_then(|value| value.get(index));
+            }
+            Segment::Map { key } => {
+                push_key(&mut field, key);
+                value = value.and_then(|value| value.get(key));
+            }
+            Segment::Enum { .. } | Segment::Unknown => {}
+        }
+    }
+
+    // Missing fields are reported by serde on the object that lacks them.
+    let message = error.inner().to_string();
+    if let Some(missing) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        push_key(&mut field, missing);
+        value = value.and_then(|value| value.get(missing));
+    }
+
+    Err(invalid(
+        field,
+        match value {
+            Some(value) => value.to_string(),
+            None => String::from("nothing"),
+        },
+    ))
+}
+
+fn push_key(field: &mut String, key: &str) {
+    if !field.is_empty() {
+        field.push('.');
+    }
+    field.push_str(key);
+}